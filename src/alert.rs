@@ -0,0 +1,101 @@
+//! SMBus alert response.
+//!
+//! When the comparator latches (see
+//! [`ComparatorLatching::Latching`](crate::ComparatorLatching::Latching)),
+//! the ALERT/RDY pin remains asserted until the conversion result is read or
+//! an SMBus alert response is sent. Several devices may share one wired-OR
+//! ALERT line. The alert response is a read from the SMBus alert response
+//! address (ARA) `0x0C`: the device asserting the line with the lowest
+//! address answers with its own address and clears its latch.
+//!
+//! ```no_run
+//! use ads1x1x::alert::{service_alerts, Responder};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let mut dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! service_alerts(&mut dev, |responder| match responder {
+//!     Responder::Ads1x1x(address) => println!("Alert from {:?}", address),
+//!     Responder::Other(address) => println!("Alert from other device at {:#x}", address),
+//! })
+//! .unwrap();
+//! ```
+
+use crate::{ic, Ads1x1x, Error, TargetAddr};
+use embedded_hal::i2c::{Error as _, ErrorKind, I2c};
+
+/// SMBus alert response address.
+pub const ALERT_RESPONSE_ADDRESS: u8 = 0x0C;
+
+/// Maximum number of alert responses handled by [`service_alerts()`].
+///
+/// This avoids looping forever if a device keeps asserting the ALERT line.
+pub const MAX_ALERT_RESPONSES: u8 = 8;
+
+/// Device that answered an alert response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Responder {
+    /// An ADS1x1x device at the given address.
+    Ads1x1x(TargetAddr),
+    /// Another device at the given 7-bit address.
+    Other(u8),
+}
+
+/// Sends an SMBus alert response and returns the device that answered.
+///
+/// The latch of the device that answered is cleared. Returns `None` if no
+/// device asserts the ALERT line (the address is not acknowledged).
+pub fn alert_response<I2C: I2c>(i2c: &mut I2C) -> Result<Option<Responder>, I2C::Error> {
+    let mut data = [0];
+    match i2c.read(ALERT_RESPONSE_ADDRESS, &mut data) {
+        Ok(()) => Ok(Some(decode(data[0]))),
+        Err(e) => match e.kind() {
+            ErrorKind::NoAcknowledge(_) => Ok(None),
+            _ => Err(e),
+        },
+    }
+}
+
+/// Sends SMBus alert responses until no device asserts the ALERT line.
+///
+/// The handler is called with each device that answered. At most
+/// [`MAX_ALERT_RESPONSES`] responses are sent. Returns the number of
+/// devices serviced.
+pub fn service_alerts<I2C, F>(i2c: &mut I2C, mut handler: F) -> Result<u8, I2C::Error>
+where
+    I2C: I2c,
+    F: FnMut(Responder),
+{
+    let mut count = 0;
+    while count < MAX_ALERT_RESPONSES {
+        match alert_response(i2c)? {
+            Some(responder) => handler(responder),
+            None => break,
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// The address is in the upper 7 bits, the lowest bit is undefined.
+fn decode(byte: u8) -> Responder {
+    let address = byte >> 1;
+    match TargetAddr::from_bits(address) {
+        Some(address) => Responder::Ads1x1x(address),
+        None => Responder::Other(address),
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+{
+    /// Sends an SMBus alert response on the bus of this device.
+    ///
+    /// Returns the device that answered, which may be another device on the
+    /// same ALERT line. See [`alert_response()`].
+    pub fn alert_response(&mut self) -> Result<Option<Responder>, Error<E>> {
+        alert_response(&mut self.i2c).map_err(Error::I2C)
+    }
+}
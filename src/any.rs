@@ -0,0 +1,229 @@
+//! Device selected at runtime.
+
+use crate::{
+    channel::ChannelSelection, ic, mode, Ads1x1x, DataRate, DynamicAdc, Error, FullScaleRange,
+    ModeChangeError, TargetAddr, Variant,
+};
+
+/// Device in any operating mode.
+#[derive(Debug)]
+pub enum AnyMode<I2C, IC, CONV> {
+    /// Device in one-shot mode.
+    OneShot(Ads1x1x<I2C, IC, CONV, mode::OneShot>),
+    /// Device in continuous conversion mode.
+    Continuous(Ads1x1x<I2C, IC, CONV, mode::Continuous>),
+}
+
+/// Any device in any operating mode.
+///
+/// This allows selecting the device at runtime without spreading the
+/// `IC` and `CONV` type parameters through the application.
+///
+/// ```no_run
+/// use ads1x1x::{channel::ChannelSelection, AnyAds1x1x, TargetAddr, Variant};
+/// use linux_embedded_hal::I2cdev;
+/// use nb::block;
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// # let variant_from_eeprom = Variant::Ads1115;
+/// let mut adc = AnyAds1x1x::new(dev, TargetAddr::default(), variant_from_eeprom);
+/// let value = block!(adc.read_normalized(ChannelSelection::SingleA0)).unwrap();
+/// ```
+#[derive(Debug)]
+pub enum AnyAds1x1x<I2C> {
+    /// ADS1013 device.
+    Ads1013(AnyMode<I2C, ic::Ads1013, ic::Resolution12Bit>),
+    /// ADS1014 device.
+    Ads1014(AnyMode<I2C, ic::Ads1014, ic::Resolution12Bit>),
+    /// ADS1015 device.
+    Ads1015(AnyMode<I2C, ic::Ads1015, ic::Resolution12Bit>),
+    /// ADS1113 device.
+    Ads1113(AnyMode<I2C, ic::Ads1113, ic::Resolution16Bit>),
+    /// ADS1114 device.
+    Ads1114(AnyMode<I2C, ic::Ads1114, ic::Resolution16Bit>),
+    /// ADS1115 device.
+    Ads1115(AnyMode<I2C, ic::Ads1115, ic::Resolution16Bit>),
+}
+
+macro_rules! dispatch {
+    ($any:expr, $dev:ident => $body:expr) => {
+        match $any {
+            AnyAds1x1x::Ads1013(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1013(AnyMode::Continuous($dev)) => $body,
+            AnyAds1x1x::Ads1014(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1014(AnyMode::Continuous($dev)) => $body,
+            AnyAds1x1x::Ads1015(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1015(AnyMode::Continuous($dev)) => $body,
+            AnyAds1x1x::Ads1113(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1113(AnyMode::Continuous($dev)) => $body,
+            AnyAds1x1x::Ads1114(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1114(AnyMode::Continuous($dev)) => $body,
+            AnyAds1x1x::Ads1115(AnyMode::OneShot($dev)) => $body,
+            AnyAds1x1x::Ads1115(AnyMode::Continuous($dev)) => $body,
+        }
+    };
+}
+
+macro_rules! change_mode {
+    ($any:expr, $from:ident, $method:ident, $($variant:ident),+) => {
+        match $any {
+            $(
+                AnyAds1x1x::$variant(AnyMode::$from(dev)) => match dev.$method() {
+                    Ok(dev) => Ok(AnyAds1x1x::$variant(AnyMode::from(dev))),
+                    Err(ModeChangeError::I2C(e, dev)) => Err(ModeChangeError::I2C(
+                        e,
+                        AnyAds1x1x::$variant(AnyMode::$from(dev)),
+                    )),
+                },
+            )+
+            any => Ok(any),
+        }
+    };
+}
+
+impl<I2C, IC, CONV> From<Ads1x1x<I2C, IC, CONV, mode::OneShot>> for AnyMode<I2C, IC, CONV> {
+    fn from(dev: Ads1x1x<I2C, IC, CONV, mode::OneShot>) -> Self {
+        AnyMode::OneShot(dev)
+    }
+}
+
+impl<I2C, IC, CONV> From<Ads1x1x<I2C, IC, CONV, mode::Continuous>> for AnyMode<I2C, IC, CONV> {
+    fn from(dev: Ads1x1x<I2C, IC, CONV, mode::Continuous>) -> Self {
+        AnyMode::Continuous(dev)
+    }
+}
+
+impl<I2C, E> AnyAds1x1x<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+{
+    /// Create a new instance of the device variant in one-shot mode.
+    pub fn new(i2c: I2C, address: TargetAddr, variant: Variant) -> Self {
+        match variant {
+            Variant::Ads1013 => AnyAds1x1x::Ads1013(Ads1x1x::new_ads1013(i2c, address).into()),
+            Variant::Ads1014 => AnyAds1x1x::Ads1014(Ads1x1x::new_ads1014(i2c, address).into()),
+            Variant::Ads1015 => AnyAds1x1x::Ads1015(Ads1x1x::new_ads1015(i2c, address).into()),
+            Variant::Ads1113 => AnyAds1x1x::Ads1113(Ads1x1x::new_ads1113(i2c, address).into()),
+            Variant::Ads1114 => AnyAds1x1x::Ads1114(Ads1x1x::new_ads1114(i2c, address).into()),
+            Variant::Ads1115 => AnyAds1x1x::Ads1115(Ads1x1x::new_ads1115(i2c, address).into()),
+        }
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        dispatch!(self, dev => dev.i2c)
+    }
+
+    /// Returns the device variant.
+    pub fn variant(&self) -> Variant {
+        match self {
+            AnyAds1x1x::Ads1013(_) => Variant::Ads1013,
+            AnyAds1x1x::Ads1014(_) => Variant::Ads1014,
+            AnyAds1x1x::Ads1015(_) => Variant::Ads1015,
+            AnyAds1x1x::Ads1113(_) => Variant::Ads1113,
+            AnyAds1x1x::Ads1114(_) => Variant::Ads1114,
+            AnyAds1x1x::Ads1115(_) => Variant::Ads1115,
+        }
+    }
+
+    /// Returns whether the device is in continuous conversion mode.
+    pub fn is_continuous(&self) -> bool {
+        matches!(
+            self,
+            AnyAds1x1x::Ads1013(AnyMode::Continuous(_))
+                | AnyAds1x1x::Ads1014(AnyMode::Continuous(_))
+                | AnyAds1x1x::Ads1015(AnyMode::Continuous(_))
+                | AnyAds1x1x::Ads1113(AnyMode::Continuous(_))
+                | AnyAds1x1x::Ads1114(AnyMode::Continuous(_))
+                | AnyAds1x1x::Ads1115(AnyMode::Continuous(_))
+        )
+    }
+
+    /// Returns the device as a trait object.
+    pub fn as_dyn(&mut self) -> &mut dyn DynamicAdc<E> {
+        dispatch!(self, dev => dev)
+    }
+
+    /// Changes to continuous operating mode.
+    ///
+    /// Does nothing if the device is already in continuous mode.
+    pub fn into_continuous(self) -> Result<Self, ModeChangeError<E, Self>> {
+        change_mode!(
+            self,
+            OneShot,
+            into_continuous,
+            Ads1013,
+            Ads1014,
+            Ads1015,
+            Ads1113,
+            Ads1114,
+            Ads1115
+        )
+    }
+
+    /// Changes to one-shot operating mode.
+    ///
+    /// Does nothing if the device is already in one-shot mode.
+    pub fn into_one_shot(self) -> Result<Self, ModeChangeError<E, Self>> {
+        change_mode!(
+            self,
+            Continuous,
+            into_one_shot,
+            Ads1013,
+            Ads1014,
+            Ads1015,
+            Ads1113,
+            Ads1114,
+            Ads1115
+        )
+    }
+
+    /// Reads a measurement on the channel normalised to a 16-bit scale.
+    ///
+    /// The results of 12-bit devices are left-justified so that for every
+    /// device the value is within `[32767..-32768]`, which corresponds to
+    /// the full-scale range selected.
+    ///
+    /// See [`DynamicAdc::read_channel()`] for the behavior in each mode.
+    pub fn read_normalized(&mut self, channel: ChannelSelection) -> nb::Result<i16, Error<E>> {
+        let shift = 16 - self.resolution_bits();
+        self.read_channel(channel).map(|value| value << shift)
+    }
+}
+
+impl<I2C, E> DynamicAdc<E> for AnyAds1x1x<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+{
+    fn resolution_bits(&self) -> u8 {
+        self.variant().resolution_bits()
+    }
+
+    fn supports_channel(&self, channel: ChannelSelection) -> bool {
+        dispatch!(self, dev => dev.supports_channel(channel))
+    }
+
+    fn supports_full_scale_range(&self) -> bool {
+        self.variant().has_comparator()
+    }
+
+    fn read_channel(&mut self, channel: ChannelSelection) -> nb::Result<i16, Error<E>> {
+        self.as_dyn().read_channel(channel)
+    }
+
+    fn data_rate(&self) -> DataRate {
+        dispatch!(self, dev => DynamicAdc::data_rate(dev))
+    }
+
+    fn set_data_rate(&mut self, rate: DataRate) -> Result<(), Error<E>> {
+        self.as_dyn().set_data_rate(rate)
+    }
+
+    fn full_scale_range(&self) -> FullScaleRange {
+        dispatch!(self, dev => DynamicAdc::full_scale_range(dev))
+    }
+
+    fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
+        self.as_dyn().set_full_scale_range(range)
+    }
+}
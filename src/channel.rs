@@ -1,34 +1,35 @@
 //! ADC input channels.
 
-use crate::{ic, Ads1x1x, BitFlags as BF, Config};
-
-use private::ChannelSelection;
+use crate::{ic, private, Ads1x1x, BitFlags as BF, Config};
 
 /// Marker type for an ADC input channel.
-pub trait ChannelId<T> {
+pub trait ChannelId<T>: private::Sealed {
     /// Get the channel.
     fn channel_id() -> ChannelSelection;
 }
 
 macro_rules! impl_channels {
     ($(#[doc = $doc:expr] $CH:ident => [$($IC:ident),+]),+ $(,)?) => {
-        mod private {
-            #[derive(Debug, Clone, Copy)]
-            /// ADC input channel selection.
-            pub enum ChannelSelection {
-                $(
-                    #[doc = $doc]
-                    $CH,
-                )+
-            }
+        /// ADC input channel selection.
+        ///
+        /// This allows selecting the channel at runtime. See [`DynamicAdc`](crate::DynamicAdc).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ChannelSelection {
+            $(
+                #[doc = $doc]
+                $CH,
+            )+
         }
 
         $(
             #[doc = $doc]
+            #[derive(Debug, Clone, Copy)]
             pub struct $CH;
 
+            impl private::Sealed for $CH {}
+
             $(
-                impl<I2C, CONV, MODE> ChannelId<Ads1x1x<I2C, ic::$IC, CONV, MODE>> for $CH {
+                impl<I2C, CONV, MODE, ALERT, DELAY> ChannelId<Ads1x1x<I2C, ic::$IC, CONV, MODE, ALERT, DELAY>> for $CH {
                     fn channel_id() -> ChannelSelection {
                         ChannelSelection::$CH
                     }
@@ -94,4 +95,20 @@ impl Config {
                 .with_high(BF::MUX0),
         }
     }
+    pub(crate) fn channel(&self) -> ChannelSelection {
+        match (
+            self.is_high(BF::MUX2),
+            self.is_high(BF::MUX1),
+            self.is_high(BF::MUX0),
+        ) {
+            (false, false, false) => ChannelSelection::DifferentialA0A1,
+            (false, false, true) => ChannelSelection::DifferentialA0A3,
+            (false, true, false) => ChannelSelection::DifferentialA1A3,
+            (false, true, true) => ChannelSelection::DifferentialA2A3,
+            (true, false, false) => ChannelSelection::SingleA0,
+            (true, false, true) => ChannelSelection::SingleA1,
+            (true, true, false) => ChannelSelection::SingleA2,
+            (true, true, true) => ChannelSelection::SingleA3,
+        }
+    }
 }
@@ -0,0 +1,38 @@
+//! Monotonic clock for deadline-based polling.
+
+/// Monotonic clock for deadline-based polling.
+///
+/// See [`read_with_clock()`](crate::Ads1x1x::read_with_clock).
+///
+/// This is implemented for closures returning the current time, so that any
+/// timer can be used:
+///
+/// ```no_run
+/// use ads1x1x::{channel, Ads1x1x, TargetAddr};
+/// use linux_embedded_hal::I2cdev;
+/// use nb::block;
+/// use std::time::Instant;
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let mut adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+/// let start = Instant::now();
+/// let mut clock = || start.elapsed().as_micros() as u32;
+/// let value = block!(adc.read_with_clock(channel::DifferentialA0A1, &mut clock)).unwrap();
+/// ```
+pub trait Clock {
+    /// Returns the current time in microseconds.
+    ///
+    /// The value must not decrease except when wrapping around.
+    fn now_us(&mut self) -> u32;
+}
+
+impl<F: FnMut() -> u32> Clock for F {
+    fn now_us(&mut self) -> u32 {
+        self()
+    }
+}
+
+/// Returns whether the deadline has been reached, taking wrap-around into account.
+pub(crate) fn deadline_reached(now_us: u32, deadline_us: u32) -> bool {
+    (now_us.wrapping_sub(deadline_us) as i32) >= 0
+}
@@ -1,6 +1,10 @@
 //! Constructor/destructor functions.
 
-use crate::{ic, mode, Ads1x1x, Config, FullScaleRange, TargetAddr};
+use crate::{
+    ic, mode,
+    types::{DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD},
+    Ads1x1x, Config, ConfigWatchdog, FullScaleRange, NoDelay, RetryPolicy, RetryStats, TargetAddr,
+};
 use core::marker::PhantomData;
 
 macro_rules! impl_new_destroy {
@@ -16,14 +20,27 @@ macro_rules! impl_new_destroy {
                     address: address.bits(),
                     config: Config::default(),
                     fsr: FullScaleRange::default(),
+                    low_threshold: DEFAULT_LOW_THRESHOLD,
+                    high_threshold: DEFAULT_HIGH_THRESHOLD,
+                    watchdog: ConfigWatchdog::default(),
+                    reads_since_check: 0,
+                    retry: RetryPolicy::default(),
+                    retry_stats: RetryStats::default(),
+                    delay: NoDelay,
+                    pointer_caching: false,
+                    pointer: None,
+                    conversion_deadline: None,
+                    supply_mv: None,
                     a_conversion_was_started: false,
+                    channel_switch: None,
                     _conv: PhantomData,
                     _ic: PhantomData,
                     _mode: PhantomData,
+                    _alert: PhantomData,
                 }
             }
         }
-        impl<I2C, CONV, MODE> Ads1x1x<I2C, ic::$IC, CONV, MODE> {
+        impl<I2C, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, ic::$IC, CONV, MODE, ALERT, DELAY> {
             /// Destroy driver instance, return I²C bus instance.
             pub fn $destroy(self) -> I2C {
                 self.i2c
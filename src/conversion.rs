@@ -1,8 +1,9 @@
-use crate::{ic, private, Error};
+use crate::{ic, private, Error, FullScaleRange};
 
 #[doc(hidden)]
 pub trait ConvertThreshold<E>: private::Sealed {
     fn convert_threshold(value: i16) -> Result<u16, Error<E>>;
+    fn convert_threshold_back(register_data: u16) -> i16;
 }
 
 impl<E> ConvertThreshold<E> for ic::Resolution12Bit {
@@ -12,12 +13,21 @@ impl<E> ConvertThreshold<E> for ic::Resolution12Bit {
         }
         Ok((value << 4) as u16)
     }
+
+    fn convert_threshold_back(register_data: u16) -> i16 {
+        // Arithmetic shift to keep the sign
+        (register_data as i16) >> 4
+    }
 }
 
 impl<E> ConvertThreshold<E> for ic::Resolution16Bit {
     fn convert_threshold(value: i16) -> Result<u16, Error<E>> {
         Ok(value as u16)
     }
+
+    fn convert_threshold_back(register_data: u16) -> i16 {
+        register_data as i16
+    }
 }
 
 #[doc(hidden)]
@@ -44,6 +54,91 @@ impl ConvertMeasurement for ic::Resolution16Bit {
     }
 }
 
+/// Size of an LSB in picovolts of 12-bit results for each full-scale range.
+const LSB_PICOVOLTS_12BIT: [u64; 6] = [
+    3_000_000_000,
+    2_000_000_000,
+    1_000_000_000,
+    500_000_000,
+    250_000_000,
+    125_000_000,
+];
+
+/// Size of an LSB in picovolts of 16-bit results for each full-scale range.
+const LSB_PICOVOLTS_16BIT: [u64; 6] = [
+    187_500_000,
+    125_000_000,
+    62_500_000,
+    31_250_000,
+    15_625_000,
+    7_812_500,
+];
+
+/// Divides rounding half away from zero.
+pub(crate) const fn div_round(numerator: i64, denominator: i64) -> i64 {
+    if numerator >= 0 {
+        (numerator + denominator / 2) / denominator
+    } else {
+        (numerator - denominator / 2) / denominator
+    }
+}
+
+impl FullScaleRange {
+    /// Returns the size of an LSB in picovolts for results of the given
+    /// resolution (12 or 16 bits).
+    ///
+    /// Any resolution other than 12 is treated as 16 bits.
+    pub const fn lsb_picovolts(self, resolution_bits: u8) -> u64 {
+        if resolution_bits == 12 {
+            LSB_PICOVOLTS_12BIT[self as usize]
+        } else {
+            LSB_PICOVOLTS_16BIT[self as usize]
+        }
+    }
+
+    /// Converts a result of the given resolution to microvolts.
+    ///
+    /// The result is rounded to the nearest microvolt. Only integer
+    /// arithmetic is used.
+    pub const fn code_to_microvolts(self, code: i16, resolution_bits: u8) -> i32 {
+        let picovolts = code as i64 * self.lsb_picovolts(resolution_bits) as i64;
+        div_round(picovolts, 1_000_000) as i32
+    }
+
+    /// Converts microvolts to the nearest code of the given resolution.
+    ///
+    /// Returns `None` if the voltage is outside of the full-scale range.
+    /// Only integer arithmetic is used.
+    pub const fn microvolts_to_code(self, microvolts: i32, resolution_bits: u8) -> Option<i16> {
+        let picovolts = microvolts as i64 * 1_000_000;
+        let code = div_round(picovolts, self.lsb_picovolts(resolution_bits) as i64);
+        let max = if resolution_bits == 12 { 2047 } else { 32767 };
+        if code < -max - 1 || code > max {
+            None
+        } else {
+            Some(code as i16)
+        }
+    }
+
+    /// Converts a result of the given resolution to nanovolts.
+    ///
+    /// The result is rounded to the nearest nanovolt. Only integer
+    /// arithmetic is used.
+    pub const fn code_to_nanovolts(self, code: i16, resolution_bits: u8) -> i64 {
+        let picovolts = code as i64 * self.lsb_picovolts(resolution_bits) as i64;
+        div_round(picovolts, 1_000)
+    }
+
+    /// Converts a result of the given resolution to volts as a fixed-point number.
+    ///
+    /// The result is rounded to the nearest representable value.
+    #[cfg(feature = "fixed")]
+    pub const fn code_to_volts_fixed(self, code: i16, resolution_bits: u8) -> fixed::types::I8F24 {
+        let nanovolts = self.code_to_nanovolts(code, resolution_bits);
+        fixed::types::I8F24::from_bits(div_round(nanovolts << 24, 1_000_000_000) as i32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +196,104 @@ mod tests {
         assert_eq!(0x7FFF, convert_threshold::<ic::Resolution16Bit>(32767));
         assert_eq!(0x8000, convert_threshold::<ic::Resolution16Bit>(-32768));
     }
+
+    fn convert_threshold_back<T: ConvertThreshold<()>>(register_data: u16) -> i16 {
+        T::convert_threshold_back(register_data)
+    }
+
+    #[test]
+    fn convert_threshold_back_12_bits() {
+        assert_eq!(0, convert_threshold_back::<ic::Resolution12Bit>(0));
+        assert_eq!(2047, convert_threshold_back::<ic::Resolution12Bit>(0x7FF0));
+        assert_eq!(2047, convert_threshold_back::<ic::Resolution12Bit>(0x7FFF));
+        assert_eq!(-2048, convert_threshold_back::<ic::Resolution12Bit>(0x8000));
+        assert_eq!(-1, convert_threshold_back::<ic::Resolution12Bit>(0xFFF0));
+    }
+
+    #[test]
+    fn convert_threshold_back_16_bits() {
+        assert_eq!(32767, convert_threshold_back::<ic::Resolution16Bit>(0x7FFF));
+        assert_eq!(
+            -32768,
+            convert_threshold_back::<ic::Resolution16Bit>(0x8000)
+        );
+    }
+
+    #[test]
+    fn lsb_sizes_match_full_scale_range() {
+        use FullScaleRange as FSR;
+        for fsr in [
+            FSR::Within6_144V,
+            FSR::Within4_096V,
+            FSR::Within2_048V,
+            FSR::Within1_024V,
+            FSR::Within0_512V,
+            FSR::Within0_256V,
+        ] {
+            let full_scale_pv = u64::from(fsr.millivolts()) * 1_000_000_000;
+            assert_eq!(full_scale_pv, fsr.lsb_picovolts(12) * 2048);
+            assert_eq!(full_scale_pv, fsr.lsb_picovolts(16) * 32768);
+        }
+    }
+
+    #[test]
+    fn convert_code_to_voltage() {
+        use FullScaleRange as FSR;
+        assert_eq!(6_143_813, FSR::Within6_144V.code_to_microvolts(32767, 16));
+        assert_eq!(-6_144_000, FSR::Within6_144V.code_to_microvolts(-32768, 16));
+        assert_eq!(6_141_000, FSR::Within6_144V.code_to_microvolts(2047, 12));
+        assert_eq!(
+            6_143_812_500,
+            FSR::Within6_144V.code_to_nanovolts(32767, 16)
+        );
+        // 7.8125µV per LSB
+        assert_eq!(8, FSR::Within0_256V.code_to_microvolts(1, 16));
+        assert_eq!(-8, FSR::Within0_256V.code_to_microvolts(-1, 16));
+        assert_eq!(16, FSR::Within0_256V.code_to_microvolts(2, 16));
+        assert_eq!(7_813, FSR::Within0_256V.code_to_nanovolts(1, 16));
+        assert_eq!(-7_813, FSR::Within0_256V.code_to_nanovolts(-1, 16));
+        assert_eq!(0, FSR::Within2_048V.code_to_nanovolts(0, 12));
+    }
+
+    #[test]
+    fn convert_voltage_to_code() {
+        use FullScaleRange as FSR;
+        assert_eq!(
+            Some(1000),
+            FSR::Within2_048V.microvolts_to_code(1_000_000, 12)
+        );
+        assert_eq!(
+            Some(-2048),
+            FSR::Within2_048V.microvolts_to_code(-2_048_000, 12)
+        );
+        assert_eq!(None, FSR::Within2_048V.microvolts_to_code(2_048_000, 12));
+        assert_eq!(
+            Some(32767),
+            FSR::Within6_144V.microvolts_to_code(6_143_813, 16)
+        );
+        assert_eq!(None, FSR::Within6_144V.microvolts_to_code(6_144_000, 16));
+        // 7.8125µV per LSB
+        assert_eq!(Some(1), FSR::Within0_256V.microvolts_to_code(8, 16));
+        assert_eq!(Some(-1), FSR::Within0_256V.microvolts_to_code(-4, 16));
+        assert_eq!(Some(0), FSR::Within0_256V.microvolts_to_code(3, 16));
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn convert_code_to_fixed_volts() {
+        use fixed::types::I8F24;
+        use FullScaleRange as FSR;
+        assert_eq!(
+            I8F24::from_num(1),
+            FSR::Within2_048V.code_to_volts_fixed(1000, 12)
+        );
+        assert_eq!(
+            I8F24::from_num(-3),
+            FSR::Within6_144V.code_to_volts_fixed(-1000, 12)
+        );
+        assert_eq!(
+            I8F24::from_num(0.0000078125),
+            FSR::Within0_256V.code_to_volts_fixed(1, 16)
+        );
+    }
 }
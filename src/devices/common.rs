@@ -1,23 +1,107 @@
 //! Common functions.
 
-use crate::{devices::OperatingMode, Ads1x1x, BitFlags, Config, Error, Register};
+use crate::{
+    devices::OperatingMode, retry, Ads1x1x, BitFlags, Config, Error, Register, RetryPolicy,
+    RetryStats,
+};
 
-impl<I2C, IC, CONV, MODE, E> Ads1x1x<I2C, IC, CONV, MODE>
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
 {
-    pub(super) fn write_register(&mut self, register: u8, data: u16) -> Result<(), Error<E>> {
+    pub(crate) fn write_register(&mut self, register: u8, data: u16) -> Result<(), Error<E>> {
         let data = data.to_be_bytes();
         let payload: [u8; 3] = [register, data[0], data[1]];
-        self.i2c.write(self.address, &payload).map_err(Error::I2C)
+        let address = self.address;
+        let result = retry::with_retries(
+            &mut self.i2c,
+            &self.retry,
+            &mut self.retry_stats,
+            &mut self.delay,
+            |i2c| i2c.write(address, &payload),
+        );
+        self.update_pointer(register, result.is_ok());
+        result.map_err(Error::I2C)
     }
 
-    pub(super) fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+    pub(crate) fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+        self.i2c_read_register(register).map_err(Error::I2C)
+    }
+
+    /// Like `read_register()` but returns the I²C bus error itself.
+    pub(crate) fn i2c_read_register(&mut self, register: u8) -> Result<u16, E> {
         let mut data = [0, 0];
-        self.i2c
-            .write_read(self.address, &[register], &mut data)
-            .map_err(Error::I2C)
-            .and(Ok(u16::from_be_bytes(data)))
+        let address = self.address;
+        // The pointer is only skipped for the conversion register, which is
+        // read over and over in continuous mode.
+        let mut skip_pointer = self.pointer_caching
+            && register == Register::CONVERSION
+            && self.pointer == Some(register);
+        let result = retry::with_retries(
+            &mut self.i2c,
+            &self.retry,
+            &mut self.retry_stats,
+            &mut self.delay,
+            |i2c| {
+                if skip_pointer {
+                    // Send the pointer again if the attempt is retried.
+                    skip_pointer = false;
+                    i2c.read(address, &mut data)
+                } else {
+                    i2c.write_read(address, &[register], &mut data)
+                }
+            },
+        );
+        self.update_pointer(register, result.is_ok());
+        result.map(|()| u16::from_be_bytes(data))
+    }
+
+    /// Keeps track of the register the device pointer points to.
+    ///
+    /// After a failed access the pointer is unknown.
+    fn update_pointer(&mut self, register: u8, success: bool) {
+        self.pointer = if success { Some(register) } else { None };
+    }
+
+    /// Enables or disables register pointer caching. Disabled by default.
+    ///
+    /// The device keeps the register pointer between transactions, so
+    /// repeated reads of the conversion register can skip sending it.
+    /// This halves the I²C traffic when reading in continuous mode.
+    ///
+    /// Only enable this if no other controller on the bus accesses the device
+    /// and nothing else (e.g. an I²C multiplexer) sits between transactions.
+    pub fn set_pointer_caching(&mut self, enabled: bool) {
+        self.pointer_caching = enabled;
+        self.pointer = None;
+    }
+
+    /// Sets the policy for retrying register accesses after transient I²C bus errors.
+    ///
+    /// See [`RetryPolicy`].
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    /// Uses the delay to wait between attempts of retried register accesses.
+    ///
+    /// See [`RetryPolicy::with_backoff()`].
+    pub fn with_retry_delay<D: embedded_hal::delay::DelayNs>(
+        self,
+        delay: D,
+    ) -> Ads1x1x<I2C, IC, CONV, MODE, ALERT, D> {
+        self.into_delay(delay)
+    }
+
+    /// Returns the statistics on retried register accesses.
+    pub fn retry_stats(&self) -> RetryStats {
+        self.retry_stats
+    }
+
+    /// Resets the statistics on retried register accesses.
+    pub fn reset_retry_stats(&mut self) {
+        self.retry_stats = RetryStats::default();
     }
 
     pub(super) fn set_operating_mode(&mut self, mode: OperatingMode) -> Result<(), Error<E>> {
@@ -2,9 +2,10 @@
 
 use crate::{ic, Ads1x1x, BitFlags as BF, DataRate12Bit, DataRate16Bit, Error, Register};
 
-impl<I2C, IC, MODE, E> Ads1x1x<I2C, IC, ic::Resolution12Bit, MODE>
+impl<I2C, IC, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, ic::Resolution12Bit, MODE, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
 {
     /// Sets the data rate.
     pub fn set_data_rate(&mut self, rate: DataRate12Bit) -> Result<(), Error<E>> {
@@ -25,9 +26,10 @@ where
     }
 }
 
-impl<I2C, IC, MODE, E> Ads1x1x<I2C, IC, ic::Resolution16Bit, MODE>
+impl<I2C, IC, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, ic::Resolution16Bit, MODE, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
 {
     /// Sets the data rate.
     pub fn set_data_rate(&mut self, rate: DataRate16Bit) -> Result<(), Error<E>> {
@@ -1,15 +1,18 @@
 //! Features only supported by ADS1x14 and ADS1x15 devices.
 
 use crate::{
-    conversion, ic, Ads1x1x, BitFlags as BF, ComparatorLatching, ComparatorMode,
-    ComparatorPolarity, ComparatorQueue, Error, FullScaleRange, Register,
+    alert_rdy, conversion, ic,
+    types::{DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD},
+    Ads1x1x, AlertRdyFunction, BitFlags as BF, ComparatorLatching, ComparatorMode,
+    ComparatorPolarity, ComparatorQueue, Config, Error, FullScaleRange, ModeChangeError, Register,
 };
 
-impl<I2C, IC, CONV, MODE, E> Ads1x1x<I2C, IC, CONV, MODE>
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
     IC: ic::Tier2Features,
-    CONV: conversion::ConvertThreshold<E>,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
 {
     /// Sets the input voltage measurable range.
     ///
@@ -42,33 +45,10 @@ where
         };
         self.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
+        self.fsr = range;
         Ok(())
     }
 
-    /// Sets the raw comparator lower threshold.
-    ///
-    /// The voltage that these values correspond to must be calculated using the
-    /// full-scale range ([`FullScaleRange`]) selected.
-    ///
-    /// The input value must be within `[2047..-2048]` for 12-bit devices (`ADS101x`)
-    /// and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
-    pub fn set_low_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
-        let register_value = CONV::convert_threshold(value)?;
-        self.write_register(Register::LOW_TH, register_value)
-    }
-
-    /// Sets the raw comparator upper threshold.
-    ///
-    /// The voltage that these values correspond to must be calculated using the
-    /// full-scale range ([`FullScaleRange`]) selected.
-    ///
-    /// The input value must be within `[2047..-2048]` for 12-bit devices (`ADS101x`)
-    /// and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
-    pub fn set_high_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
-        let register_value = CONV::convert_threshold(value)?;
-        self.write_register(Register::HIGH_TH, register_value)
-    }
-
     /// Sets the comparator mode.
     pub fn set_comparator_mode(&mut self, mode: ComparatorMode) -> Result<(), Error<E>> {
         let config = match mode {
@@ -108,10 +88,118 @@ where
         Ok(())
     }
 
-    /// Activates the comparator and sets the alert queue.
+    /// Enables the comparator with the given alert queue.
     ///
-    /// The comparator can be disabled with [`disable_comparator`](Self::disable_comparator).
-    pub fn set_comparator_queue(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
+    /// If the ALERT/RDY pin was used as conversion-ready pin, the thresholds
+    /// are reset to the defaults first.
+    ///
+    /// In case of error, the device is returned in its previous state.
+    /// The registers written before the error keep their new values in the
+    /// device, which the driver keeps track of, e.g. for
+    /// [`resync_config()`](Self::resync_config).
+    #[allow(clippy::type_complexity)]
+    pub fn into_comparator(
+        mut self,
+        queue: ComparatorQueue,
+    ) -> Result<Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::Comparator, DELAY>, ModeChangeError<E, Self>>
+    {
+        if let Err(Error::I2C(e)) = self.enable_comparator(queue) {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        Ok(self.into_alert_rdy())
+    }
+
+    /// Uses the ALERT/RDY pin as conversion-ready pin.
+    ///
+    /// When in one-shot mode, this makes the ALERT/RDY pin output the OS bit,
+    /// in continuous-conversion mode, provides a continuous-conversion ready pulse.
+    ///
+    /// This sets the MSB of the upper threshold to 1 and the MSB of the lower
+    /// threshold to 0 and enables the comparator if it was disabled.
+    ///
+    /// In case of error, the device is returned in its previous state.
+    /// The registers written before the error keep their new values in the
+    /// device, which the driver keeps track of, e.g. for
+    /// [`resync_config()`](Self::resync_config).
+    #[allow(clippy::type_complexity)]
+    pub fn into_conversion_ready(
+        mut self,
+    ) -> Result<
+        Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::ConversionReady, DELAY>,
+        ModeChangeError<E, Self>,
+    > {
+        if let Err(Error::I2C(e)) = self.enable_conversion_ready() {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        Ok(self.into_alert_rdy())
+    }
+
+    /// Disables the comparator and sets the ALERT/RDY pin to high-impedance. (default)
+    ///
+    /// The thresholds are kept.
+    ///
+    /// In case of error, the device is returned unchanged.
+    #[allow(clippy::type_complexity)]
+    pub fn into_alert_rdy_disabled(
+        mut self,
+    ) -> Result<Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::Disabled, DELAY>, ModeChangeError<E, Self>>
+    {
+        let config = self
+            .config
+            .with_high(BF::COMP_QUE1)
+            .with_high(BF::COMP_QUE0);
+        if let Err(Error::I2C(e)) = self.write_register(Register::CONFIG, config.bits) {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.config = config;
+        Ok(self.into_alert_rdy())
+    }
+
+    /// Reads the function of the ALERT/RDY pin configured in the device.
+    ///
+    /// This allows finding out the state of a device that was configured
+    /// before, for example after a warm reboot of the controller.
+    pub fn read_alert_rdy_function(&mut self) -> Result<AlertRdyFunction, Error<E>> {
+        let config = Config {
+            bits: self.read_register(Register::CONFIG)?,
+        };
+        if is_comparator_disabled(&config) {
+            return Ok(AlertRdyFunction::Disabled);
+        }
+        let high_threshold = self.read_register(Register::HIGH_TH)?;
+        let low_threshold = self.read_register(Register::LOW_TH)?;
+        if is_conversion_ready(low_threshold, high_threshold) {
+            Ok(AlertRdyFunction::ConversionReady)
+        } else {
+            Ok(AlertRdyFunction::Comparator)
+        }
+    }
+
+    fn enable_comparator(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
+        if is_conversion_ready(self.low_threshold, self.high_threshold) {
+            // Raise the upper threshold first so that no alert is asserted.
+            self.write_register(Register::HIGH_TH, DEFAULT_HIGH_THRESHOLD)?;
+            self.high_threshold = DEFAULT_HIGH_THRESHOLD;
+            self.write_register(Register::LOW_TH, DEFAULT_LOW_THRESHOLD)?;
+            self.low_threshold = DEFAULT_LOW_THRESHOLD;
+        }
+        self.write_comparator_queue(queue)
+    }
+
+    fn enable_conversion_ready(&mut self) -> Result<(), Error<E>> {
+        // Clear the lower threshold first so that no alert is asserted
+        // while the comparator is still active.
+        self.write_register(Register::LOW_TH, 0)?;
+        self.low_threshold = 0;
+        self.write_register(Register::HIGH_TH, 0x8000)?;
+        self.high_threshold = 0x8000;
+        if is_comparator_disabled(&self.config) {
+            self.write_comparator_queue(ComparatorQueue::default())?;
+        }
+        Ok(())
+    }
+
+    fn write_comparator_queue(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
         let config = match queue {
             ComparatorQueue::One => self.config.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0),
             ComparatorQueue::Two => self.config.with_low(BF::COMP_QUE1).with_high(BF::COMP_QUE0),
@@ -121,39 +209,62 @@ where
         self.config = config;
         Ok(())
     }
+}
 
-    /// Disables the comparator. (default)
+impl<I2C, IC, CONV, MODE, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::Comparator, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
+{
+    /// Sets the raw comparator lower threshold.
     ///
-    /// This sets the ALERT/RDY pin to high-impedance.
+    /// The voltage that these values correspond to must be calculated using the
+    /// full-scale range ([`FullScaleRange`]) selected.
     ///
-    /// The comparator can be enabled by setting the comparator queue using
-    /// the [`set_comparator_queue`](Self::set_comparator_queue) method.
-    pub fn disable_comparator(&mut self) -> Result<(), Error<E>> {
-        let config = self
-            .config
-            .with_high(BF::COMP_QUE1)
-            .with_high(BF::COMP_QUE0);
-        self.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
+    /// The input value must be within `[2047..-2048]` for 12-bit devices (`ADS101x`)
+    /// and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
+    ///
+    /// The value may be outside of the [usable code range](Self::usable_code_range),
+    /// for example to disable one side of a window.
+    /// See [`thresholds_reachable()`](Self::thresholds_reachable).
+    pub fn set_low_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
+        let register_value = CONV::convert_threshold(value)?;
+        self.write_register(Register::LOW_TH, register_value)?;
+        self.low_threshold = register_value;
         Ok(())
     }
 
-    /// Enables the ALERT/RDY pin as conversion-ready function.
+    /// Sets the raw comparator upper threshold.
     ///
-    /// When in one-shot mode, this makes the ALERT/RDY pin output the OS bit,
-    /// in continuous-conversion mode, provides a continuous-conversion ready pulse.
+    /// The voltage that these values correspond to must be calculated using the
+    /// full-scale range ([`FullScaleRange`]) selected.
     ///
-    /// When calling this the comparator will be reset to default and any thresholds will be cleared.
-    pub fn use_alert_rdy_pin_as_ready(&mut self) -> Result<(), Error<E>> {
-        if self.config
-            != self
-                .config
-                .with_high(BF::COMP_QUE1)
-                .with_high(BF::COMP_QUE0)
-        {
-            self.set_comparator_queue(ComparatorQueue::default())?;
-        }
-        self.write_register(Register::HIGH_TH, 0x8000)?;
-        self.write_register(Register::LOW_TH, 0)
+    /// The input value must be within `[2047..-2048]` for 12-bit devices (`ADS101x`)
+    /// and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
+    ///
+    /// The value may be outside of the [usable code range](Self::usable_code_range),
+    /// for example to disable one side of a window.
+    /// See [`thresholds_reachable()`](Self::thresholds_reachable).
+    pub fn set_high_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
+        let register_value = CONV::convert_threshold(value)?;
+        self.write_register(Register::HIGH_TH, register_value)?;
+        self.high_threshold = register_value;
+        Ok(())
+    }
+
+    /// Sets the comparator alert queue.
+    pub fn set_comparator_queue(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
+        self.write_comparator_queue(queue)
     }
 }
+
+fn is_comparator_disabled(config: &Config) -> bool {
+    config.is_high(BF::COMP_QUE1) && config.is_high(BF::COMP_QUE0)
+}
+
+/// The MSB of the upper threshold is 1 and the MSB of the lower threshold is 0.
+fn is_conversion_ready(low_threshold: u16, high_threshold: u16) -> bool {
+    high_threshold & 0x8000 != 0 && low_threshold & 0x8000 == 0
+}
@@ -7,3 +7,4 @@ enum OperatingMode {
 mod common;
 mod features;
 mod mode;
+mod watchdog;
@@ -1,36 +1,38 @@
 //! Continuous measurement mode.
 
 use crate::{
-    conversion, devices::OperatingMode, mode, Ads1x1x, ChannelId, Error, ModeChangeError, Register,
+    channel::ChannelSelection, conversion, devices::OperatingMode, mode, Ads1x1x, BitFlags as BF,
+    ChannelId, Error, ModeChangeError, Register,
 };
-use core::marker::PhantomData;
-
-impl<I2C, IC, CONV, E> Ads1x1x<I2C, IC, CONV, mode::Continuous>
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::Continuous, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
     CONV: conversion::ConvertMeasurement,
 {
     /// Changes to one-shot operating mode.
+    #[allow(clippy::type_complexity)]
     pub fn into_one_shot(
         mut self,
-    ) -> Result<Ads1x1x<I2C, IC, CONV, mode::OneShot>, ModeChangeError<E, Self>> {
+    ) -> Result<Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>, ModeChangeError<E, Self>> {
         if let Err(Error::I2C(e)) = self.set_operating_mode(OperatingMode::OneShot) {
             return Err(ModeChangeError::I2C(e, self));
         }
-        Ok(Ads1x1x {
-            i2c: self.i2c,
-            address: self.address,
-            config: self.config,
-            fsr: self.fsr,
-            a_conversion_was_started: false,
-            _conv: PhantomData,
-            _ic: PhantomData,
-            _mode: PhantomData,
-        })
+        Ok(self.into_mode(false))
     }
 
     /// Reads the most recent measurement.
+    ///
+    /// If a channel change made by [`read_channel()`](crate::DynamicAdc::read_channel)
+    /// is still pending, this waits for the conversion on the new channel.
     pub fn read(&mut self) -> Result<i16, Error<E>> {
+        if self.channel_switch.is_some() {
+            let channel = self.config.channel();
+            return nb::block!(self.read_selection(channel));
+        }
+        if self.config_check_due() {
+            self.verify_config()?;
+        }
         let value = self.read_register(Register::CONVERSION)?;
         Ok(CONV::convert_measurement(value))
     }
@@ -42,9 +44,93 @@ where
     /// The following conversions will use the new channel configuration.
     #[allow(unused_variables)]
     pub fn select_channel<CH: ChannelId<Self>>(&mut self, channel: CH) -> Result<(), Error<E>> {
-        let config = self.config.with_mux_bits(CH::channel_id());
+        // This also resumes continuous conversions after an abandoned channel change.
+        let config = self
+            .config
+            .with_mux_bits(CH::channel_id())
+            .with_low(BF::OP_MODE);
         self.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
+        self.channel_switch = None;
         Ok(())
     }
+
+    /// Reads the most recent measurement of the channel.
+    ///
+    /// When the channel changes, the result of the ongoing conversion would
+    /// belong to the previous channel. Instead, conversions are stopped, a
+    /// single conversion is made on the new channel using the OS bit and then
+    /// continuous conversions are resumed.
+    ///
+    /// Should the change fail, continuous conversions are resumed on the
+    /// previous channel.
+    pub(crate) fn read_selection(
+        &mut self,
+        channel: ChannelSelection,
+    ) -> nb::Result<i16, Error<E>> {
+        let result = self.switch_channel(channel);
+        if let Err(nb::Error::Other(_)) = result {
+            self.abandon_channel_switch();
+        }
+        result
+    }
+
+    fn switch_channel(&mut self, channel: ChannelSelection) -> nb::Result<i16, Error<E>> {
+        let continuous = self.config.with_mux_bits(channel).with_low(BF::OP_MODE);
+        if self.config == continuous {
+            return self.read().map_err(nb::Error::Other);
+        }
+        let single = continuous.with_high(BF::OP_MODE);
+        if self.config != single {
+            self.write_register(Register::CONFIG, single.with_low(BF::OS).bits)
+                .map_err(nb::Error::Other)?;
+            if self.channel_switch.is_none() {
+                self.channel_switch = Some(self.config.channel());
+            }
+            self.config = single;
+            self.a_conversion_was_started = false;
+            return Err(nb::Error::WouldBlock);
+        }
+        let status = self
+            .read_register(Register::CONFIG)
+            .map_err(nb::Error::Other)?;
+        if self.config_check_due() {
+            self.check_config(status).map_err(nb::Error::Other)?;
+        }
+        if status & BF::OS == 0 {
+            return Err(nb::Error::WouldBlock);
+        }
+        if !self.a_conversion_was_started {
+            self.write_register(Register::CONFIG, single.with_high(BF::OS).bits)
+                .map_err(nb::Error::Other)?;
+            self.a_conversion_was_started = true;
+            return Err(nb::Error::WouldBlock);
+        }
+        let value = self
+            .read_register(Register::CONVERSION)
+            .map_err(nb::Error::Other)?;
+        self.a_conversion_was_started = false;
+        self.write_register(Register::CONFIG, continuous.bits)
+            .map_err(nb::Error::Other)?;
+        self.config = continuous;
+        self.a_conversion_was_started = true;
+        self.channel_switch = None;
+        Ok(CONV::convert_measurement(value))
+    }
+
+    /// Resumes continuous conversions on the channel selected before a
+    /// failed channel change.
+    ///
+    /// Should this fail as well, the change stays pending.
+    fn abandon_channel_switch(&mut self) {
+        let Some(previous) = self.channel_switch else {
+            return;
+        };
+        let config = self.config.with_mux_bits(previous).with_low(BF::OP_MODE);
+        if self.write_register(Register::CONFIG, config.bits).is_ok() {
+            self.config = config;
+            self.a_conversion_was_started = true;
+            self.channel_switch = None;
+        }
+    }
 }
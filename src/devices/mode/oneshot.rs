@@ -1,45 +1,38 @@
 //! One-shot measurement mode.
 
-use core::marker::PhantomData;
-
 use crate::{
-    conversion, devices::OperatingMode, mode, Ads1x1x, BitFlags, ChannelId, Config, Error,
-    ModeChangeError, Register,
+    channel::ChannelSelection, clock, conversion, devices::OperatingMode, ic, mode, Ads1x1x,
+    BitFlags, ChannelId, Clock, Config, DataRate, Error, ModeChangeError, Register,
 };
 
-impl<I2C, IC, CONV, E> Ads1x1x<I2C, IC, CONV, mode::OneShot>
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
     CONV: conversion::ConvertMeasurement,
 {
     /// Changes to continuous operating mode.
+    #[allow(clippy::type_complexity)]
     pub fn into_continuous(
         mut self,
-    ) -> Result<Ads1x1x<I2C, IC, CONV, mode::Continuous>, ModeChangeError<E, Self>> {
+    ) -> Result<Ads1x1x<I2C, IC, CONV, mode::Continuous, ALERT, DELAY>, ModeChangeError<E, Self>>
+    {
         if let Err(Error::I2C(e)) = self.set_operating_mode(OperatingMode::Continuous) {
             return Err(ModeChangeError::I2C(e, self));
         }
-        Ok(Ads1x1x {
-            i2c: self.i2c,
-            address: self.address,
-            config: self.config,
-            fsr: self.fsr,
-            a_conversion_was_started: true,
-            _conv: PhantomData,
-            _ic: PhantomData,
-            _mode: PhantomData,
-        })
+        Ok(self.into_mode(true))
     }
 
-    fn trigger_measurement(&mut self, config: &Config) -> Result<(), Error<E>> {
+    pub(crate) fn trigger_measurement(&mut self, config: &Config) -> Result<(), Error<E>> {
         let config = config.with_high(BitFlags::OS);
         self.write_register(Register::CONFIG, config.bits)
     }
 }
 
-impl<I2C, IC, CONV, E> Ads1x1x<I2C, IC, CONV, mode::OneShot>
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
 where
     I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
     CONV: conversion::ConvertMeasurement,
 {
     /// Requests that the ADC begins a conversion on the specified channel.
@@ -56,13 +49,23 @@ where
     /// using the new channel selection is triggered.
     #[allow(unused_variables)]
     pub fn read<CH: ChannelId<Self>>(&mut self, channel: CH) -> nb::Result<i16, Error<E>> {
-        if self
-            .is_measurement_in_progress()
-            .map_err(nb::Error::Other)?
-        {
+        self.read_selection(CH::channel_id())
+    }
+
+    pub(crate) fn read_selection(
+        &mut self,
+        channel: ChannelSelection,
+    ) -> nb::Result<i16, Error<E>> {
+        let status = self
+            .read_register(Register::CONFIG)
+            .map_err(nb::Error::Other)?;
+        if self.config_check_due() {
+            self.check_config(status).map_err(nb::Error::Other)?;
+        }
+        if status & BitFlags::OS == 0 {
             return Err(nb::Error::WouldBlock);
         }
-        let config = self.config.with_mux_bits(CH::channel_id());
+        let config = self.config.with_mux_bits(channel);
         let same_channel = self.config == config;
         if self.a_conversion_was_started && same_channel {
             // result is ready
@@ -76,6 +79,68 @@ where
             .map_err(nb::Error::Other)?;
         self.config = config;
         self.a_conversion_was_started = true;
+        self.conversion_deadline = None;
         Err(nb::Error::WouldBlock)
     }
 }
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Like [`read()`](Self::read) but avoids polling the device while a
+    /// conversion cannot have finished yet.
+    ///
+    /// When a conversion is triggered, the time at which it should be
+    /// complete is calculated from the configured data rate and the clock.
+    /// Until then `nb::Error::WouldBlock` is returned without any bus access.
+    /// Afterwards the device is polled like in [`read()`](Self::read).
+    ///
+    /// Returns [`Error::Timeout`] if the conversion has not finished after
+    /// twice the worst-case conversion time, e.g. because the device was
+    /// reset. The next read then triggers a new conversion.
+    #[allow(unused_variables)]
+    pub fn read_with_clock<CH, C>(
+        &mut self,
+        channel: CH,
+        clock: &mut C,
+    ) -> nb::Result<i16, Error<E>>
+    where
+        CH: ChannelId<Self>,
+        C: Clock,
+    {
+        let conversion_time_us = self.max_conversion_time_us();
+        let mut overdue = false;
+        if self.a_conversion_was_started {
+            if let Some(deadline) = self.conversion_deadline {
+                let now = clock.now_us();
+                if !clock::deadline_reached(now, deadline) {
+                    return Err(nb::Error::WouldBlock);
+                }
+                let timeout = deadline.wrapping_add(conversion_time_us);
+                overdue = clock::deadline_reached(now, timeout);
+            }
+        }
+        let result = self.read_selection(CH::channel_id());
+        // The deadline is only kept if the same conversion is still ongoing.
+        if overdue
+            && self.conversion_deadline.is_some()
+            && matches!(result, Err(nb::Error::WouldBlock))
+        {
+            self.a_conversion_was_started = false;
+            self.conversion_deadline = None;
+            return Err(nb::Error::Other(Error::Timeout));
+        }
+        if self.a_conversion_was_started && self.conversion_deadline.is_none() {
+            let now = clock.now_us();
+            self.conversion_deadline = Some(now.wrapping_add(conversion_time_us));
+        }
+        result
+    }
+
+    fn max_conversion_time_us(&self) -> u32 {
+        DataRate::from_bits(self.config.data_rate_bits(), CONV::BITS).max_conversion_time_us()
+    }
+}
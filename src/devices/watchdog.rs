@@ -0,0 +1,80 @@
+//! Configuration verification and recovery after a device reset.
+
+use crate::{Ads1x1x, BitFlags, Config, ConfigWatchdog, Error, Register};
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+{
+    /// Sets the policy for verifying the device configuration on reads.
+    ///
+    /// See [`ConfigWatchdog`].
+    pub fn set_config_watchdog(&mut self, watchdog: ConfigWatchdog) {
+        self.watchdog = watchdog;
+        self.reads_since_check = 0;
+    }
+
+    /// Verifies the device configuration and restores it if necessary.
+    ///
+    /// The CONFIG register is read and compared with the configuration set
+    /// through the driver. If they differ, the full configuration including
+    /// the comparator thresholds is written again and this returns
+    /// [`Error::DeviceReset`] if the device was found in its default
+    /// configuration or [`Error::ConfigMismatch`] otherwise.
+    ///
+    /// This can be called periodically or it can be done automatically on
+    /// reads. See [`set_config_watchdog()`](Self::set_config_watchdog).
+    pub fn verify_config(&mut self) -> Result<(), Error<E>> {
+        let actual = self.read_register(Register::CONFIG)?;
+        self.check_config(actual)
+    }
+
+    /// Compares a CONFIG register value read from the device with the
+    /// expected configuration and restores it if they differ.
+    pub(crate) fn check_config(&mut self, actual: u16) -> Result<(), Error<E>> {
+        // When reading, the OS bit reports the conversion status.
+        let expected = self.config.with_low(BitFlags::OS).bits;
+        let actual_without_os = actual & !BitFlags::OS;
+        if actual_without_os == expected {
+            return Ok(());
+        }
+        self.resync_config()?;
+        if actual_without_os == Config::default().with_low(BitFlags::OS).bits {
+            Err(Error::DeviceReset)
+        } else {
+            Err(Error::ConfigMismatch { expected, actual })
+        }
+    }
+
+    /// Writes the full configuration to the device again, including the
+    /// comparator thresholds.
+    ///
+    /// This is useful after a bus recovery or a device reset.
+    /// No conversion is triggered in one-shot mode. A conversion that was
+    /// requested before is considered lost.
+    pub fn resync_config(&mut self) -> Result<(), Error<E>> {
+        self.write_register(Register::CONFIG, self.config.with_low(BitFlags::OS).bits)?;
+        self.write_register(Register::LOW_TH, self.low_threshold)?;
+        self.write_register(Register::HIGH_TH, self.high_threshold)?;
+        self.a_conversion_was_started = false;
+        Ok(())
+    }
+
+    /// Returns whether the configuration should be verified on this read.
+    pub(crate) fn config_check_due(&mut self) -> bool {
+        match self.watchdog {
+            ConfigWatchdog::Disabled => false,
+            ConfigWatchdog::EveryRead => true,
+            ConfigWatchdog::EveryNReads(n) => {
+                self.reads_since_check = self.reads_since_check.saturating_add(1);
+                if self.reads_since_check >= n {
+                    self.reads_since_check = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Object-safe interface to any device.
+
+use crate::{
+    channel::ChannelSelection, conversion, ic, mode, Ads1x1x, DataRate, Error, FullScaleRange,
+    Register,
+};
+
+/// Object-safe interface implemented by every device in every operating mode.
+///
+/// This allows storing different devices or devices in different operating
+/// modes behind a trait object like `&mut dyn DynamicAdc<E>`.
+///
+/// Features not available on a device are reported with [`Error::NotSupported`].
+pub trait DynamicAdc<E> {
+    /// Returns the number of bits of a conversion result (12 or 16).
+    fn resolution_bits(&self) -> u8;
+
+    /// Returns whether the device can measure on the channel.
+    fn supports_channel(&self, channel: ChannelSelection) -> bool;
+
+    /// Returns whether the device has a programmable gain amplifier and a comparator.
+    fn supports_full_scale_range(&self) -> bool;
+
+    /// Reads a measurement on the channel.
+    ///
+    /// In one-shot mode this behaves like the one-shot `read()`.
+    ///
+    /// In continuous mode this returns the most recent measurement if the
+    /// channel is selected. Otherwise `nb::Error::WouldBlock` is returned
+    /// until a conversion on the new channel has finished. For this,
+    /// continuous conversions are stopped, a single conversion is made on
+    /// the new channel and then continuous conversions are resumed.
+    /// Should an error occur meanwhile, continuous conversions are resumed
+    /// on the previous channel.
+    ///
+    /// Returns [`Error::NotSupported`] if the device cannot measure on the channel.
+    fn read_channel(&mut self, channel: ChannelSelection) -> nb::Result<i16, Error<E>>;
+
+    /// Returns the configured data rate.
+    fn data_rate(&self) -> DataRate;
+
+    /// Sets the data rate.
+    ///
+    /// Returns [`Error::InvalidInputData`] if the data rate does not belong to
+    /// the resolution of the device.
+    fn set_data_rate(&mut self, rate: DataRate) -> Result<(), Error<E>>;
+
+    /// Returns the configured full-scale range.
+    ///
+    /// This is always ±2.048V on ADS1x13 devices.
+    fn full_scale_range(&self) -> FullScaleRange;
+
+    /// Sets the full-scale range.
+    ///
+    /// Returns [`Error::NotSupported`] on ADS1x13 devices.
+    fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>>;
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::IcFeatures,
+    CONV: ic::Resolution,
+{
+    pub(crate) fn supports(channel: ChannelSelection) -> bool {
+        IC::MULTIPLEXER || channel == ChannelSelection::DifferentialA0A1
+    }
+
+    fn dynamic_data_rate(&self) -> DataRate {
+        DataRate::from_bits(self.config.data_rate_bits(), CONV::BITS)
+    }
+
+    fn set_dynamic_data_rate(&mut self, rate: DataRate) -> Result<(), Error<E>> {
+        let bits = match rate {
+            DataRate::Rate12Bit(rate) if CONV::BITS == 12 => rate as u16,
+            DataRate::Rate16Bit(rate) if CONV::BITS == 16 => rate as u16,
+            _ => return Err(Error::InvalidInputData),
+        };
+        let config = self.config.with_data_rate_bits(bits);
+        self.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        Ok(())
+    }
+
+    fn set_dynamic_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
+        if !IC::TIER2 {
+            return Err(Error::NotSupported);
+        }
+        let config = self.config.with_full_scale_range(range);
+        self.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        self.fsr = range;
+        Ok(())
+    }
+}
+
+macro_rules! impl_dynamic_adc {
+    ($mode:ty) => {
+        impl<I2C, IC, CONV, ALERT, DELAY, E> DynamicAdc<E>
+            for Ads1x1x<I2C, IC, CONV, $mode, ALERT, DELAY>
+        where
+            I2C: embedded_hal::i2c::I2c<Error = E>,
+            DELAY: embedded_hal::delay::DelayNs,
+            IC: ic::IcFeatures,
+            CONV: ic::Resolution + conversion::ConvertMeasurement,
+        {
+            fn resolution_bits(&self) -> u8 {
+                CONV::BITS
+            }
+
+            fn supports_channel(&self, channel: ChannelSelection) -> bool {
+                Self::supports(channel)
+            }
+
+            fn supports_full_scale_range(&self) -> bool {
+                IC::TIER2
+            }
+
+            fn read_channel(&mut self, channel: ChannelSelection) -> nb::Result<i16, Error<E>> {
+                if !Self::supports(channel) {
+                    return Err(nb::Error::Other(Error::NotSupported));
+                }
+                self.read_selection(channel)
+            }
+
+            fn data_rate(&self) -> DataRate {
+                self.dynamic_data_rate()
+            }
+
+            fn set_data_rate(&mut self, rate: DataRate) -> Result<(), Error<E>> {
+                self.set_dynamic_data_rate(rate)
+            }
+
+            fn full_scale_range(&self) -> FullScaleRange {
+                self.config.full_scale_range()
+            }
+
+            fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
+                self.set_dynamic_full_scale_range(range)
+            }
+        }
+    };
+}
+
+impl_dynamic_adc!(mode::OneShot);
+impl_dynamic_adc!(mode::Continuous);
@@ -1,14 +1,17 @@
 /// ICs
-use crate::private;
+use crate::{private, Variant};
 
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct Resolution12Bit;
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct Resolution16Bit;
 
 macro_rules! ic_marker {
     ($name:ident) => {
         /// IC marker
+        #[derive(Debug)]
         pub struct $name(());
     };
 }
@@ -20,6 +23,45 @@ ic_marker!(Ads1114);
 ic_marker!(Ads1015);
 ic_marker!(Ads1115);
 
+/// Resolution of the conversion results.
+pub trait Resolution: private::Sealed {
+    /// Number of bits of a conversion result.
+    const BITS: u8;
+}
+
+impl Resolution for Resolution12Bit {
+    const BITS: u8 = 12;
+}
+
+impl Resolution for Resolution16Bit {
+    const BITS: u8 = 16;
+}
+
+/// Features available on an IC.
+pub trait IcFeatures: private::Sealed {
+    /// Runtime identifier of the IC.
+    const VARIANT: Variant;
+    /// Whether the IC has an input multiplexer (ADS1x15).
+    const MULTIPLEXER: bool = Self::VARIANT.has_multiplexer();
+    /// Whether the IC has a programmable gain amplifier and a comparator (ADS1x14, ADS1x15).
+    const TIER2: bool = Self::VARIANT.has_comparator();
+}
+
+macro_rules! ic_features {
+    ($name:ident) => {
+        impl IcFeatures for $name {
+            const VARIANT: Variant = Variant::$name;
+        }
+    };
+}
+
+ic_features!(Ads1013);
+ic_features!(Ads1113);
+ic_features!(Ads1014);
+ic_features!(Ads1114);
+ic_features!(Ads1015);
+ic_features!(Ads1115);
+
 pub trait Tier2Features: private::Sealed {}
 
 macro_rules! tier2_features {
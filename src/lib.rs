@@ -12,14 +12,41 @@
 //! - Set the data rate. See: [`set_data_rate()`].
 //! - Set the full-scale range (gain amplifier). See [`set_full_scale_range()`].
 //! - Read whether a measurement is in progress. See: [`is_measurement_in_progress()`].
-//! - Set the ALERT/RDY pin to be used as conversion-ready pin. See: [`use_alert_rdy_pin_as_ready()`].
+//! - Set the ALERT/RDY pin to be used as conversion-ready pin. See: [`into_conversion_ready()`].
+//! - Read the function of the ALERT/RDY pin configured in the device. See: [`read_alert_rdy_function()`].
 //! - Comparator:
+//!     - Enable the comparator. See: [`into_comparator()`].
 //!     - Set the low and high thresholds. See: [`set_high_threshold_raw()`].
+//!     - Set a trip point with hysteresis or a window. See: [`set_trip_point_raw()`] and [`set_window_raw()`].
+//!     - Set the thresholds in microvolts. See: [`set_trip_point_microvolts()`].
+//!     - Read the thresholds back from the device. See: [`read_thresholds_raw()`].
 //!     - Set the comparator mode. See: [`set_comparator_mode()`].
 //!     - Set the comparator polarity. See: [`set_comparator_polarity()`].
 //!     - Set the comparator latching. See: [`set_comparator_latching()`].
 //!     - Set the comparator queue. See: [`set_comparator_queue()`].
-//!     - Disable the comparator. See: [`disable_comparator()`].
+//!     - Disable the comparator. See: [`into_alert_rdy_disabled()`].
+//! - Share a bus with other devices behind a TCA9548A I²C multiplexer. See: [`mux`].
+//! - Split the device into independently owned channel handles. See: [`split()`].
+//! - Use different devices in different modes through a trait object. See: [`DynamicAdc`].
+//! - Select the device at runtime. See: [`AnyAds1x1x`].
+//! - Probe for the presence and family of a device. See: [`probe`].
+//! - Verify the configuration and restore it after a device reset. See: [`verify_config()`].
+//! - Retry register accesses after transient I²C bus errors. See: [`set_retry_policy()`].
+//! - Recover a hung I²C bus via GPIO bit-banging. See: [`recovery`].
+//! - Skip sending the register pointer on repeated conversion reads. See: [`set_pointer_caching()`].
+//! - Poll one-shot measurements based on a deadline instead of the bus. See: [`read_with_clock()`].
+//! - Sample several channels in one-shot mode keeping the ADC busy. See: [`sequencer`].
+//! - Rotate through several channels in continuous conversion mode. See: [`scanner`].
+//! - Read a measurement together with its settings. See: [`read_measurement()`].
+//! - Read measurements on a common 16-bit scale for every device. See: [`read_normalized()`].
+//! - Convert results to microvolts or nanovolts using integer arithmetic only. See: [`code_to_microvolts()`].
+//!   With the `fixed` feature enabled, results can also be converted to fixed-point volts.
+//! - Get datasheet metadata like conversion times and noise. See: [`max_conversion_time_us()`].
+//! - Use typed physical units with the `uom` feature enabled. See: [`read_voltage()`] and [`set_low_threshold()`].
+//! - Check input voltages against the supply voltage. See: [`with_supply_millivolts()`].
+//! - Watch thresholds of several channels in software on any device. See: [`soft_comparator`].
+//! - Find out which device asserted a shared ALERT line and clear its latch. See: [`alert`].
+//! - Let the device watch a threshold at low power while the controller sleeps. See: [`monitor`].
 //!
 //! [`into_continuous()`]: struct.Ads1x1x.html#method.into_continuous
 //! [read_os]: struct.Ads1x1x.html#method.read-1
@@ -29,12 +56,30 @@
 //! [`set_full_scale_range()`]: struct.Ads1x1x.html#method.set_full_scale_range
 //! [`is_measurement_in_progress()`]: struct.Ads1x1x.html#method.is_measurement_in_progress
 //! [`set_high_threshold_raw()`]: struct.Ads1x1x.html#method.set_high_threshold_raw
+//! [`set_trip_point_raw()`]: struct.Ads1x1x.html#method.set_trip_point_raw
+//! [`set_window_raw()`]: struct.Ads1x1x.html#method.set_window_raw
+//! [`set_trip_point_microvolts()`]: struct.Ads1x1x.html#method.set_trip_point_microvolts
+//! [`read_thresholds_raw()`]: struct.Ads1x1x.html#method.read_thresholds_raw
 //! [`set_comparator_mode()`]: struct.Ads1x1x.html#method.set_comparator_mode
 //! [`set_comparator_polarity()`]: struct.Ads1x1x.html#method.set_comparator_polarity
 //! [`set_comparator_latching()`]: struct.Ads1x1x.html#method.set_comparator_latching
 //! [`set_comparator_queue()`]: struct.Ads1x1x.html#method.set_comparator_queue
-//! [`disable_comparator()`]: struct.Ads1x1x.html#method.disable_comparator
-//! [`use_alert_rdy_pin_as_ready()`]: struct.Ads1x1x.html#method.use_alert_rdy_pin_as_ready
+//! [`into_comparator()`]: struct.Ads1x1x.html#method.into_comparator
+//! [`into_alert_rdy_disabled()`]: struct.Ads1x1x.html#method.into_alert_rdy_disabled
+//! [`into_conversion_ready()`]: struct.Ads1x1x.html#method.into_conversion_ready
+//! [`read_alert_rdy_function()`]: struct.Ads1x1x.html#method.read_alert_rdy_function
+//! [`split()`]: struct.Ads1x1x.html#method.split
+//! [`verify_config()`]: struct.Ads1x1x.html#method.verify_config
+//! [`code_to_microvolts()`]: enum.FullScaleRange.html#method.code_to_microvolts
+//! [`max_conversion_time_us()`]: enum.DataRate16Bit.html#method.max_conversion_time_us
+//! [`read_measurement()`]: struct.Ads1x1x.html#method.read_measurement
+//! [`read_normalized()`]: struct.Ads1x1x.html#method.read_normalized
+//! [`read_voltage()`]: struct.Ads1x1x.html#method.read_voltage
+//! [`set_low_threshold()`]: struct.Ads1x1x.html#method.set_low_threshold
+//! [`with_supply_millivolts()`]: struct.Ads1x1x.html#method.with_supply_millivolts
+//! [`read_with_clock()`]: struct.Ads1x1x.html#method.read_with_clock
+//! [`set_pointer_caching()`]: struct.Ads1x1x.html#method.set_pointer_caching
+//! [`set_retry_policy()`]: struct.Ads1x1x.html#method.set_retry_policy
 //!
 //! # The devices
 //!
@@ -158,6 +203,10 @@
 //!
 //! ## Configuring the Comparator
 //!
+//! The function of the ALERT/RDY pin (disabled, comparator or
+//! conversion-ready) is part of the type of the driver, like the operating
+//! mode. The thresholds can only be set once the comparator is enabled.
+//!
 //! Configure the comparator to assert when the voltage drops below -1.5V
 //! or goes above 1.5V in at least two consecutive conversions. Then the
 //! ALERT/RDY pin will be set high and it will be kept so until the
@@ -174,13 +223,13 @@
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let address = TargetAddr::default();
 //! let mut adc = Ads1x1x::new_ads1015(dev, address);
-//! adc.set_comparator_queue(ComparatorQueue::Two).unwrap();
 //! adc.set_comparator_polarity(ComparatorPolarity::ActiveHigh).unwrap();
 //! adc.set_comparator_mode(ComparatorMode::Window).unwrap();
+//! adc.set_comparator_latching(ComparatorLatching::Latching).unwrap();
 //! adc.set_full_scale_range(FullScaleRange::Within2_048V).unwrap();
+//! let mut adc = adc.into_comparator(ComparatorQueue::Two).ok().unwrap();
 //! adc.set_low_threshold_raw(-1500).unwrap();
 //! adc.set_high_threshold_raw(1500).unwrap();
-//! adc.set_comparator_latching(ComparatorLatching::Latching).unwrap();
 //! ```
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
@@ -214,26 +263,51 @@ impl BitFlags {
     const COMP_QUE0: u16 = 0b0000_0000_0000_0001;
 }
 
+pub mod alert;
 pub mod channel;
 pub use channel::ChannelId;
+mod any;
+pub use crate::any::{AnyAds1x1x, AnyMode};
+mod clock;
+pub use crate::clock::Clock;
 mod construction;
 mod conversion;
 pub use crate::conversion::{ConvertMeasurement, ConvertThreshold};
 mod devices;
+mod dynamic;
+pub use crate::dynamic::DynamicAdc;
 #[doc(hidden)]
 pub mod ic;
+mod measurement;
+mod metadata;
+pub mod monitor;
+pub use crate::measurement::Measurement;
+pub mod mux;
+pub mod probe;
+pub mod recovery;
+mod retry;
+pub use crate::retry::{NoDelay, RetryPolicy, RetryStats};
+pub mod scanner;
+pub mod sequencer;
+pub mod soft_comparator;
+pub mod split;
+mod supply;
+mod thresholds;
 mod types;
+#[cfg(feature = "uom")]
+mod units;
 use crate::types::Config;
 pub use crate::types::{
-    mode, Ads1x1x, ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue,
-    DataRate12Bit, DataRate16Bit, Error, FullScaleRange, ModeChangeError, TargetAddr,
+    alert_rdy, mode, Ads1x1x, AlertRdyFunction, ComparatorLatching, ComparatorMode,
+    ComparatorPolarity, ComparatorQueue, ConfigWatchdog, DataRate, DataRate12Bit, DataRate16Bit,
+    Error, FullScaleRange, ModeChangeError, TargetAddr, Variant,
 };
 
 mod private {
     use super::{ic, Ads1x1x};
     pub trait Sealed {}
 
-    impl<I2C, IC, CONV, MODE> Sealed for Ads1x1x<I2C, IC, CONV, MODE> {}
+    impl<I2C, IC, CONV, MODE, ALERT, DELAY> Sealed for Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY> {}
 
     impl Sealed for ic::Resolution12Bit {}
     impl Sealed for ic::Resolution16Bit {}
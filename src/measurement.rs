@@ -0,0 +1,186 @@
+//! Measurement results with metadata and normalised results.
+
+use crate::{
+    channel::ChannelSelection, conversion, ic, mode, Ads1x1x, ChannelId, DataRate, Error,
+    FullScaleRange,
+};
+
+/// Measurement result together with the settings that produced it.
+///
+/// See [`read_measurement()`](crate::Ads1x1x::read_measurement).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Measurement {
+    raw: i16,
+    channel: ChannelSelection,
+    full_scale_range: FullScaleRange,
+    data_rate: DataRate,
+    resolution_bits: u8,
+    at_supply_limit: bool,
+}
+
+impl Measurement {
+    /// Returns the raw conversion result.
+    ///
+    /// This is within `[2047..-2048]` for 12-bit devices and within
+    /// `[32767..-32768]` for 16-bit devices.
+    pub const fn raw(&self) -> i16 {
+        self.raw
+    }
+
+    /// Returns the channel measured.
+    pub const fn channel(&self) -> ChannelSelection {
+        self.channel
+    }
+
+    /// Returns the full-scale range used.
+    pub const fn full_scale_range(&self) -> FullScaleRange {
+        self.full_scale_range
+    }
+
+    /// Returns the data rate used.
+    pub const fn data_rate(&self) -> DataRate {
+        self.data_rate
+    }
+
+    /// Returns the number of bits of the conversion result (12 or 16).
+    pub const fn resolution_bits(&self) -> u8 {
+        self.resolution_bits
+    }
+
+    /// Returns whether the result is at the limit of the full-scale range.
+    ///
+    /// In this case the input voltage may be outside of the full-scale range.
+    pub const fn is_clipped(&self) -> bool {
+        let max = (1_i32 << (self.resolution_bits - 1)) - 1;
+        let raw = self.raw as i32;
+        raw >= max || raw < -max
+    }
+
+    /// Returns whether the result is at the limit imposed by the supply
+    /// voltage rather than at the limit of the full-scale range.
+    ///
+    /// In this case the input voltage may be beyond the supply rails.
+    /// This is only detected if the supply voltage was declared with
+    /// [`with_supply_millivolts()`](crate::Ads1x1x::with_supply_millivolts).
+    pub const fn is_at_supply_limit(&self) -> bool {
+        self.at_supply_limit
+    }
+
+    /// Returns the result normalised to a 16-bit scale (Q15 format).
+    ///
+    /// The full-scale range corresponds to `[-1.0..1.0)`.
+    pub const fn normalized(&self) -> i16 {
+        self.raw << (16 - self.resolution_bits)
+    }
+
+    /// Returns the input voltage in microvolts.
+    ///
+    /// See [`FullScaleRange::code_to_microvolts()`].
+    pub const fn microvolts(&self) -> i32 {
+        self.full_scale_range
+            .code_to_microvolts(self.raw, self.resolution_bits)
+    }
+
+    /// Returns the input voltage in nanovolts.
+    ///
+    /// See [`FullScaleRange::code_to_nanovolts()`].
+    pub const fn nanovolts(&self) -> i64 {
+        self.full_scale_range
+            .code_to_nanovolts(self.raw, self.resolution_bits)
+    }
+
+    /// Returns the input voltage in volts as a fixed-point number.
+    ///
+    /// See [`FullScaleRange::code_to_volts_fixed()`].
+    #[cfg(feature = "fixed")]
+    pub const fn volts_fixed(&self) -> fixed::types::I8F24 {
+        self.full_scale_range
+            .code_to_volts_fixed(self.raw, self.resolution_bits)
+    }
+
+    /// Returns the input voltage in volts.
+    pub fn volts(&self) -> f32 {
+        f32::from(self.normalized()) * f32::from(self.full_scale_range.millivolts())
+            / (32768.0 * 1000.0)
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    CONV: ic::Resolution,
+{
+    fn measurement(&self, raw: i16) -> Measurement {
+        Measurement {
+            raw,
+            channel: self.config.channel(),
+            full_scale_range: self.fsr,
+            data_rate: DataRate::from_bits(self.config.data_rate_bits(), CONV::BITS),
+            resolution_bits: CONV::BITS,
+            at_supply_limit: self.is_at_supply_limit(raw),
+        }
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Like [`read()`](Self::read) but returns the result together with the
+    /// settings that produced it.
+    #[allow(unused_variables)]
+    pub fn read_measurement<CH: ChannelId<Self>>(
+        &mut self,
+        channel: CH,
+    ) -> nb::Result<Measurement, Error<E>> {
+        let raw = self.read_selection(CH::channel_id())?;
+        Ok(self.measurement(raw))
+    }
+
+    /// Like [`read()`](Self::read) but returns the result normalised to a
+    /// 16-bit scale (Q15 format) for every device.
+    ///
+    /// The results of 12-bit devices are left-justified so that for every
+    /// device the value is within `[32767..-32768]`, which corresponds to
+    /// the full-scale range selected. This allows comparing the results of
+    /// ADS101x and ADS111x devices directly.
+    #[allow(unused_variables)]
+    pub fn read_normalized<CH: ChannelId<Self>>(
+        &mut self,
+        channel: CH,
+    ) -> nb::Result<i16, Error<E>> {
+        let raw = self.read_selection(CH::channel_id())?;
+        Ok(normalize::<CONV>(raw))
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::Continuous, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Like [`read()`](Self::read) but returns the result together with the
+    /// current settings.
+    ///
+    /// Note that right after changing the channel, the most recent result
+    /// may still belong to the previous channel.
+    pub fn read_measurement(&mut self) -> Result<Measurement, Error<E>> {
+        let raw = self.read()?;
+        Ok(self.measurement(raw))
+    }
+
+    /// Like [`read()`](Self::read) but returns the result normalised to a
+    /// 16-bit scale (Q15 format) for every device.
+    ///
+    /// See the one-shot [`read_normalized()`](Ads1x1x::read_normalized).
+    pub fn read_normalized(&mut self) -> Result<i16, Error<E>> {
+        self.read().map(normalize::<CONV>)
+    }
+}
+
+/// Left-justifies a conversion result.
+fn normalize<CONV: ic::Resolution>(raw: i16) -> i16 {
+    raw << (16 - CONV::BITS)
+}
@@ -0,0 +1,178 @@
+//! Datasheet metadata.
+//!
+//! The LSB size of each full-scale range is available through
+//! [`FullScaleRange::lsb_picovolts()`].
+
+use crate::{ic, DataRate, DataRate12Bit, DataRate16Bit, FullScaleRange};
+
+/// Peak-to-peak noise in picovolts of ADS111x devices at VDD = 3.3V for each
+/// data rate (rows) and full-scale range (columns).
+///
+/// See Table 1 in the ADS111x datasheet. The RMS noise is below one LSB for
+/// every setting.
+#[rustfmt::skip]
+const NOISE_PEAK_TO_PEAK_PICOVOLTS_16BIT: [[u64; 6]; 8] = [
+    // ±6.144V      ±4.096V      ±2.048V      ±1.024V     ±0.512V     ±0.256V
+    [187_500_000, 125_000_000,  62_500_000, 31_250_000, 15_625_000,  7_812_500], // 8 SPS
+    [187_500_000, 125_000_000,  62_500_000, 31_250_000, 15_625_000,  7_812_500], // 16 SPS
+    [187_500_000, 125_000_000,  62_500_000, 31_250_000, 15_625_000,  7_812_500], // 32 SPS
+    [187_500_000, 125_000_000,  62_500_000, 31_250_000, 15_625_000,  7_812_500], // 64 SPS
+    [187_500_000, 125_000_000,  62_500_000, 31_250_000, 15_625_000, 12_350_000], // 128 SPS
+    [252_090_000, 148_280_000,  84_030_000, 39_540_000, 16_060_000, 18_530_000], // 250 SPS
+    [266_920_000, 227_380_000,  79_080_000, 56_840_000, 32_130_000, 24_220_000], // 475 SPS
+    [430_060_000, 266_930_000, 118_630_000, 64_260_000, 40_780_000, 37_060_000], // 860 SPS
+];
+
+impl DataRate12Bit {
+    /// Returns the nominal conversion time in microseconds, rounded up.
+    pub const fn nominal_conversion_time_us(self) -> u32 {
+        conversion_time_us(self.samples_per_second(), 10)
+    }
+
+    /// Returns the worst-case conversion time in microseconds, rounded up.
+    ///
+    /// The data rate of the internal oscillator varies by up to 10%.
+    pub const fn max_conversion_time_us(self) -> u32 {
+        conversion_time_us(self.samples_per_second(), 9)
+    }
+
+    /// Returns the RMS noise in picovolts for the full-scale range.
+    ///
+    /// The noise of ADS101x devices is below one LSB for every setting.
+    pub const fn noise_rms_picovolts(self, range: FullScaleRange) -> u64 {
+        range.lsb_picovolts(12)
+    }
+
+    /// Returns the peak-to-peak noise in picovolts for the full-scale range.
+    ///
+    /// The noise of ADS101x devices is below one LSB for every setting.
+    pub const fn noise_peak_to_peak_picovolts(self, range: FullScaleRange) -> u64 {
+        range.lsb_picovolts(12)
+    }
+
+    /// Returns the effective number of bits (ENOB).
+    ///
+    /// As the RMS noise is below one LSB for every setting, this is the
+    /// resolution for every full-scale range.
+    pub const fn effective_bits(self) -> u8 {
+        12
+    }
+}
+
+impl DataRate16Bit {
+    /// Returns the nominal conversion time in microseconds, rounded up.
+    pub const fn nominal_conversion_time_us(self) -> u32 {
+        conversion_time_us(self.samples_per_second(), 10)
+    }
+
+    /// Returns the worst-case conversion time in microseconds, rounded up.
+    ///
+    /// The data rate of the internal oscillator varies by up to 10%.
+    pub const fn max_conversion_time_us(self) -> u32 {
+        conversion_time_us(self.samples_per_second(), 9)
+    }
+
+    /// Returns the RMS noise in picovolts for the full-scale range.
+    ///
+    /// The noise of ADS111x devices is below one LSB for every setting.
+    pub const fn noise_rms_picovolts(self, range: FullScaleRange) -> u64 {
+        range.lsb_picovolts(16)
+    }
+
+    /// Returns the peak-to-peak noise in picovolts for the full-scale range
+    /// at VDD = 3.3V.
+    pub const fn noise_peak_to_peak_picovolts(self, range: FullScaleRange) -> u64 {
+        NOISE_PEAK_TO_PEAK_PICOVOLTS_16BIT[self as usize][range as usize]
+    }
+
+    /// Returns the effective number of bits (ENOB).
+    ///
+    /// As the RMS noise is below one LSB for every setting, this is the
+    /// resolution for every full-scale range.
+    pub const fn effective_bits(self) -> u8 {
+        16
+    }
+}
+
+impl DataRate {
+    /// Returns the nominal conversion time in microseconds, rounded up.
+    pub const fn nominal_conversion_time_us(self) -> u32 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.nominal_conversion_time_us(),
+            DataRate::Rate16Bit(rate) => rate.nominal_conversion_time_us(),
+        }
+    }
+
+    /// Returns the worst-case conversion time in microseconds, rounded up.
+    ///
+    /// The data rate of the internal oscillator varies by up to 10%.
+    pub const fn max_conversion_time_us(self) -> u32 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.max_conversion_time_us(),
+            DataRate::Rate16Bit(rate) => rate.max_conversion_time_us(),
+        }
+    }
+
+    /// Returns the RMS noise in picovolts for the full-scale range.
+    pub const fn noise_rms_picovolts(self, range: FullScaleRange) -> u64 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.noise_rms_picovolts(range),
+            DataRate::Rate16Bit(rate) => rate.noise_rms_picovolts(range),
+        }
+    }
+
+    /// Returns the peak-to-peak noise in picovolts for the full-scale range.
+    pub const fn noise_peak_to_peak_picovolts(self, range: FullScaleRange) -> u64 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.noise_peak_to_peak_picovolts(range),
+            DataRate::Rate16Bit(rate) => rate.noise_peak_to_peak_picovolts(range),
+        }
+    }
+
+    /// Returns the effective number of bits (ENOB).
+    pub const fn effective_bits(self) -> u8 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.effective_bits(),
+            DataRate::Rate16Bit(rate) => rate.effective_bits(),
+        }
+    }
+}
+
+macro_rules! ic_metadata {
+    ($name:ident, $bits:expr, $rate:ident, $default:ident, $max:ident) => {
+        impl ic::$name {
+            /// Returns the number of bits of a conversion result.
+            pub const fn resolution_bits() -> u8 {
+                $bits
+            }
+
+            /// Returns the size of one LSB in picovolts for the full-scale range.
+            pub const fn lsb_picovolts(range: FullScaleRange) -> u64 {
+                range.lsb_picovolts($bits)
+            }
+
+            /// Returns the data rate after a reset.
+            pub const fn default_data_rate() -> $rate {
+                $rate::$default
+            }
+
+            /// Returns the highest data rate.
+            pub const fn max_data_rate() -> $rate {
+                $rate::$max
+            }
+        }
+    };
+}
+
+ic_metadata!(Ads1013, 12, DataRate12Bit, Sps1600, Sps3300);
+ic_metadata!(Ads1014, 12, DataRate12Bit, Sps1600, Sps3300);
+ic_metadata!(Ads1015, 12, DataRate12Bit, Sps1600, Sps3300);
+ic_metadata!(Ads1113, 16, DataRate16Bit, Sps128, Sps860);
+ic_metadata!(Ads1114, 16, DataRate16Bit, Sps128, Sps860);
+ic_metadata!(Ads1115, 16, DataRate16Bit, Sps128, Sps860);
+
+/// Conversion time at the given fraction (in tenths) of the nominal data rate.
+const fn conversion_time_us(samples_per_second: u16, tenths: u32) -> u32 {
+    let divisor = samples_per_second as u32 * tenths;
+    10_000_000_u32.div_ceil(divisor)
+}
@@ -0,0 +1,138 @@
+//! Low-power monitoring of a threshold.
+//!
+//! A [`Monitor`] lets the device watch one channel on its own in continuous
+//! conversion mode at the slowest data rate with a latching comparator, so
+//! that the controller can sleep until the ALERT/RDY pin is asserted.
+//!
+//! The comparator thresholds, mode, polarity and queue are configured
+//! beforehand as usual. When the alert fires, [`Monitor::on_alert()`] reads
+//! the value that triggered it, clears the latch and returns the device to
+//! one-shot mode, where it is powered down between measurements.
+//!
+//! ```no_run
+//! use ads1x1x::{channel, Ads1x1x, ComparatorQueue, TargetAddr};
+//! use embedded_hal::digital::InputPin;
+//! use linux_embedded_hal::{CdevPin, I2cdev};
+//! # fn alert_rdy_pin() -> CdevPin { unimplemented!() }
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+//! let mut adc = adc.into_comparator(ComparatorQueue::One).ok().unwrap();
+//! adc.set_trip_point_raw(20000, 500).unwrap();
+//! let monitor = adc.into_monitor(channel::SingleA0).ok().unwrap();
+//! let mut alert_rdy = alert_rdy_pin();
+//! // Sleep until the alert is asserted (active low by default)
+//! while alert_rdy.is_high().unwrap() {}
+//! let (value, adc) = monitor.on_alert().ok().unwrap();
+//! println!("Threshold crossed: {}", value);
+//! ```
+
+use crate::{
+    alert_rdy, conversion, ic, mode, Ads1x1x, BitFlags as BF, ChannelId, Config, Error,
+    ModeChangeError, NoDelay, Register,
+};
+
+/// Device watching a threshold in continuous conversion mode.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct Monitor<I2C, IC, CONV, DELAY = NoDelay> {
+    adc: Ads1x1x<I2C, IC, CONV, mode::Continuous, alert_rdy::Comparator, DELAY>,
+    /// Configuration to restore when monitoring ends.
+    previous: Config,
+}
+
+impl<I2C, IC, CONV, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, alert_rdy::Comparator, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Starts watching the specified channel.
+    ///
+    /// Selects the channel, the slowest data rate and a latching comparator
+    /// and starts continuous conversion mode with a single configuration
+    /// write. The rest of the configuration is kept.
+    ///
+    /// In case of error, the device is returned unchanged.
+    #[allow(unused_variables, clippy::type_complexity)]
+    pub fn into_monitor<CH: ChannelId<Self>>(
+        mut self,
+        channel: CH,
+    ) -> Result<Monitor<I2C, IC, CONV, DELAY>, ModeChangeError<E, Self>> {
+        let previous = self.config.clone();
+        // The slowest data rate has the lowest setting on all devices.
+        let config = previous
+            .with_mux_bits(CH::channel_id())
+            .with_data_rate_bits(0)
+            .with_high(BF::COMP_LAT)
+            .with_low(BF::OP_MODE);
+        if let Err(Error::I2C(e)) = self.write_register(Register::CONFIG, config.bits) {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.config = config;
+        Ok(Monitor {
+            adc: self.into_mode(true),
+            previous,
+        })
+    }
+}
+
+impl<I2C, IC, CONV, DELAY, E> Monitor<I2C, IC, CONV, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Handles the alert and stops monitoring.
+    ///
+    /// Reads the most recent result, which clears the latch, and restores
+    /// the configuration from before monitoring, which puts the device back
+    /// into one-shot mode. Returns the result and the device.
+    ///
+    /// Should another conversion complete in between and latch the alert
+    /// again, it remains asserted until the next one-shot measurement is read
+    /// or an alert response is sent (see [`alert`](crate::alert)).
+    ///
+    /// In case of error, the monitor is returned and keeps running.
+    #[allow(clippy::type_complexity)]
+    pub fn on_alert(
+        mut self,
+    ) -> Result<
+        (
+            i16,
+            Ads1x1x<I2C, IC, CONV, mode::OneShot, alert_rdy::Comparator, DELAY>,
+        ),
+        ModeChangeError<E, Self>,
+    > {
+        let value = match self.adc.i2c_read_register(Register::CONVERSION) {
+            Ok(value) => CONV::convert_measurement(value),
+            Err(e) => return Err(ModeChangeError::I2C(e, self)),
+        };
+        self.stop().map(|adc| (value, adc))
+    }
+
+    /// Stops monitoring without handling an alert.
+    ///
+    /// Restores the configuration from before monitoring, which puts the
+    /// device back into one-shot mode. A latched alert is not cleared.
+    ///
+    /// In case of error, the monitor is returned and keeps running.
+    #[allow(clippy::type_complexity)]
+    pub fn stop(
+        mut self,
+    ) -> Result<
+        Ads1x1x<I2C, IC, CONV, mode::OneShot, alert_rdy::Comparator, DELAY>,
+        ModeChangeError<E, Self>,
+    > {
+        if let Err(Error::I2C(e)) = self
+            .adc
+            .write_register(Register::CONFIG, self.previous.bits)
+        {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.adc.config = self.previous;
+        Ok(self.adc.into_mode(false))
+    }
+}
@@ -0,0 +1,168 @@
+//! TCA9548A I²C multiplexer integration.
+//!
+//! The TCA9548A splits one I²C bus into eight downstream segments so that
+//! several devices with the same address can be connected to the same
+//! controller.
+//!
+//! A [`Tca9548a`] takes ownership of the upstream bus and hands out
+//! [`MuxSegment`]s. Each segment implements the `embedded-hal` I²C traits
+//! and selects its downstream segment before every transaction, so it can be
+//! passed to any of the `Ads1x1x::new_*` constructors. The register accesses
+//! done by the driver then always reach the ADC on the right segment.
+//!
+//! The multiplexer remembers the currently selected segment, so consecutive
+//! transactions on the same segment do not cause additional traffic.
+//!
+//! ```no_run
+//! use ads1x1x::{channel, mux::Tca9548a, Ads1x1x, TargetAddr};
+//! use linux_embedded_hal::I2cdev;
+//! use nb::block;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mux = Tca9548a::new(dev, 0x70);
+//! let mut adc0 = Ads1x1x::new_ads1115(mux.segment(0).unwrap(), TargetAddr::default());
+//! let mut adc1 = Ads1x1x::new_ads1115(mux.segment(1).unwrap(), TargetAddr::default());
+//! let value0 = block!(adc0.read(channel::SingleA0)).unwrap();
+//! let value1 = block!(adc1.read(channel::SingleA0)).unwrap();
+//! ```
+
+use core::cell::RefCell;
+use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+
+/// Errors when communicating through the I²C multiplexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MuxError<E> {
+    /// I²C bus error while selecting the multiplexer segment.
+    Select(E),
+    /// I²C bus error while communicating with the device on the segment.
+    Device(E),
+}
+
+impl<E> embedded_hal::i2c::Error for MuxError<E>
+where
+    E: embedded_hal::i2c::Error,
+{
+    fn kind(&self) -> ErrorKind {
+        match self {
+            MuxError::Select(e) | MuxError::Device(e) => e.kind(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MuxState<I2C> {
+    i2c: I2C,
+    selected: Option<u8>,
+}
+
+/// TCA9548A I²C multiplexer.
+#[derive(Debug)]
+pub struct Tca9548a<I2C> {
+    address: u8,
+    state: RefCell<MuxState<I2C>>,
+}
+
+impl<I2C> Tca9548a<I2C> {
+    /// Number of downstream segments.
+    pub const SEGMENT_COUNT: u8 = 8;
+
+    /// Create a new instance for the multiplexer at the given 7-bit address.
+    ///
+    /// The address is `0x70` when the A0, A1 and A2 pins are connected to GND.
+    pub fn new(i2c: I2C, address: u8) -> Self {
+        Tca9548a {
+            address,
+            state: RefCell::new(MuxState {
+                i2c,
+                selected: None,
+            }),
+        }
+    }
+
+    /// Destroy the multiplexer instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.state.into_inner().i2c
+    }
+
+    /// Get an I²C bus handle for one of the downstream segments.
+    ///
+    /// Returns `None` if the segment index is not within `[0..7]`.
+    pub fn segment(&self, index: u8) -> Option<MuxSegment<'_, I2C>> {
+        if index < Self::SEGMENT_COUNT {
+            Some(MuxSegment { mux: self, index })
+        } else {
+            None
+        }
+    }
+
+    /// Forget the currently selected segment.
+    ///
+    /// The segment will be selected again before the next transaction.
+    /// This is useful if another controller may have changed the selection.
+    pub fn invalidate_selection(&self) {
+        self.state.borrow_mut().selected = None;
+    }
+}
+
+/// I²C bus handle for a downstream segment of a [`Tca9548a`].
+#[derive(Debug)]
+pub struct MuxSegment<'a, I2C> {
+    mux: &'a Tca9548a<I2C>,
+    index: u8,
+}
+
+impl<I2C> MuxSegment<'_, I2C> {
+    /// Index of the downstream segment within `[0..7]`.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+}
+
+impl<I2C: I2c> ErrorType for MuxSegment<'_, I2C> {
+    type Error = MuxError<I2C::Error>;
+}
+
+impl<I2C: I2c> MuxSegment<'_, I2C> {
+    fn with_segment<R>(
+        &mut self,
+        f: impl FnOnce(&mut I2C) -> Result<R, I2C::Error>,
+    ) -> Result<R, MuxError<I2C::Error>> {
+        let mut state = self.mux.state.borrow_mut();
+        if state.selected != Some(self.index) {
+            state.selected = None;
+            state
+                .i2c
+                .write(self.mux.address, &[1 << self.index])
+                .map_err(MuxError::Select)?;
+            state.selected = Some(self.index);
+        }
+        f(&mut state.i2c).map_err(MuxError::Device)
+    }
+}
+
+impl<I2C: I2c> I2c for MuxSegment<'_, I2C> {
+    fn read(&mut self, address: u8, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_segment(|i2c| i2c.read(address, read))
+    }
+
+    fn write(&mut self, address: u8, write: &[u8]) -> Result<(), Self::Error> {
+        self.with_segment(|i2c| i2c.write(address, write))
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.with_segment(|i2c| i2c.write_read(address, write, read))
+    }
+
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.with_segment(|i2c| i2c.transaction(address, operations))
+    }
+}
@@ -0,0 +1,196 @@
+//! Best-effort device presence and variant probing.
+//!
+//! [`probe()`] checks whether a device answers at an address and tries to
+//! tell apart 12-bit (ADS101x) from 16-bit (ADS111x) devices:
+//!
+//! 1. The CONFIG register is read. A NACK means that no device is present.
+//! 2. A one-shot conversion at the slowest data rate is started and the
+//!    CONFIG register is read back to confirm that it holds what was written.
+//! 3. The conversion time is measured. At the slowest data rate a
+//!    conversion takes about 8ms on ADS101x devices and about 125ms on
+//!    ADS111x devices.
+//! 4. The four LSBs of the conversion result are checked. These are always
+//!    zero on ADS101x devices.
+//!
+//! The original CONFIG register value is restored at the end.
+//!
+//! Note that probing cannot tell apart devices of the same family
+//! (e.g. ADS1013 and ADS1015).
+//!
+//! ```no_run
+//! use ads1x1x::{probe::probe, TargetAddr};
+//! use linux_embedded_hal::{Delay, I2cdev};
+//!
+//! let mut dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! for address in TargetAddr::ALL {
+//!     let result = probe(&mut dev, address, &mut Delay).unwrap();
+//!     println!("{:?}: {:?}", address, result);
+//! }
+//! ```
+
+use crate::{BitFlags, Register, TargetAddr};
+use embedded_hal::{
+    delay::DelayNs,
+    i2c::{Error as _, ErrorKind, I2c},
+};
+
+/// Device family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// 12-bit devices: ADS1013, ADS1014 and ADS1015.
+    Ads101x,
+    /// 16-bit devices: ADS1113, ADS1114 and ADS1115.
+    Ads111x,
+}
+
+/// Confidence of a probing result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum Confidence {
+    /// Only some of the checks agree.
+    Low,
+    /// All checks agree but they are not conclusive.
+    Medium,
+    /// All checks agree and they are conclusive.
+    High,
+}
+
+/// Result of probing an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProbeResult {
+    /// No device acknowledged the address.
+    NoDevice,
+    /// A device acknowledged the address but it does not behave like an ADS1x1x.
+    Unknown,
+    /// An ADS1x1x device was detected.
+    Detected {
+        /// Guessed device family.
+        family: Family,
+        /// Confidence of the guess.
+        confidence: Confidence,
+    },
+}
+
+/// Time to wait before checking whether the conversion has finished.
+///
+/// This is longer than the worst-case conversion time of ADS101x devices at
+/// 128 SPS and much shorter than that of ADS111x devices at 8 SPS.
+const SHORT_WAIT_MS: u32 = 20;
+/// Polling interval while waiting for the conversion of ADS111x devices.
+const POLL_INTERVAL_MS: u32 = 10;
+/// Maximum time to wait for a conversion.
+const TIMEOUT_MS: u32 = 200;
+
+/// Probes the address for an ADS1x1x device.
+///
+/// This takes up to 200ms and changes the device configuration temporarily.
+/// A conversion result may be lost.
+///
+/// Bus errors other than a NACK are returned. The configuration is restored
+/// even if probing fails. In that case the error of probing is returned
+/// rather than that of restoring the configuration.
+pub fn probe<I2C, D>(
+    i2c: &mut I2C,
+    address: TargetAddr,
+    delay: &mut D,
+) -> Result<ProbeResult, I2C::Error>
+where
+    I2C: I2c,
+    D: DelayNs,
+{
+    let address = address.bits();
+    let original = match read_register(i2c, address, Register::CONFIG) {
+        Ok(config) => config,
+        Err(e) => match e.kind() {
+            ErrorKind::NoAcknowledge(_) => return Ok(ProbeResult::NoDevice),
+            _ => return Err(e),
+        },
+    };
+    let result = measure(i2c, address, delay, original);
+    // Restore the configuration without triggering a conversion.
+    let restored = write_register(i2c, address, Register::CONFIG, original & !BitFlags::OS);
+    let result = result?;
+    restored?;
+    Ok(result)
+}
+
+fn measure<I2C, D>(
+    i2c: &mut I2C,
+    address: u8,
+    delay: &mut D,
+    original: u16,
+) -> Result<ProbeResult, I2C::Error>
+where
+    I2C: I2c,
+    D: DelayNs,
+{
+    const DATA_RATE_MASK: u16 = BitFlags::DR2 | BitFlags::DR1 | BitFlags::DR0;
+    const DEFAULT_CONFIG: u16 = 0x8583;
+    let config = (original & !DATA_RATE_MASK) | BitFlags::OS | BitFlags::OP_MODE;
+    write_register(i2c, address, Register::CONFIG, config)?;
+    let readback = read_register(i2c, address, Register::CONFIG)?;
+    if readback & !BitFlags::OS != config & !BitFlags::OS {
+        return Ok(ProbeResult::Unknown);
+    }
+
+    delay.delay_ms(SHORT_WAIT_MS);
+    let mut elapsed_ms = SHORT_WAIT_MS;
+    let fast = is_ready(i2c, address)?;
+    let mut ready = fast;
+    while !ready && elapsed_ms < TIMEOUT_MS {
+        delay.delay_ms(POLL_INTERVAL_MS);
+        elapsed_ms += POLL_INTERVAL_MS;
+        ready = is_ready(i2c, address)?;
+    }
+    if !ready {
+        return Ok(ProbeResult::Unknown);
+    }
+    let value = read_register(i2c, address, Register::CONVERSION)?;
+    let lsbs_set = value & 0b1111 != 0;
+    let reset_config = original == DEFAULT_CONFIG;
+
+    let result = match (fast, lsbs_set) {
+        (true, false) => ProbeResult::Detected {
+            family: Family::Ads101x,
+            confidence: if reset_config {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            },
+        },
+        (false, true) => ProbeResult::Detected {
+            family: Family::Ads111x,
+            confidence: Confidence::High,
+        },
+        // The LSBs of a 16-bit result may be zero by chance.
+        (false, false) => ProbeResult::Detected {
+            family: Family::Ads111x,
+            confidence: Confidence::Medium,
+        },
+        // Timing and result do not agree.
+        (true, true) => ProbeResult::Detected {
+            family: Family::Ads111x,
+            confidence: Confidence::Low,
+        },
+    };
+    Ok(result)
+}
+
+fn is_ready<I2C: I2c>(i2c: &mut I2C, address: u8) -> Result<bool, I2C::Error> {
+    Ok(read_register(i2c, address, Register::CONFIG)? & BitFlags::OS != 0)
+}
+
+fn read_register<I2C: I2c>(i2c: &mut I2C, address: u8, register: u8) -> Result<u16, I2C::Error> {
+    let mut data = [0, 0];
+    i2c.write_read(address, &[register], &mut data)?;
+    Ok(u16::from_be_bytes(data))
+}
+
+fn write_register<I2C: I2c>(
+    i2c: &mut I2C,
+    address: u8,
+    register: u8,
+    data: u16,
+) -> Result<(), I2C::Error> {
+    let data = data.to_be_bytes();
+    i2c.write(address, &[register, data[0], data[1]])
+}
@@ -0,0 +1,108 @@
+//! I²C bus recovery via GPIO bit-banging.
+//!
+//! If a read is interrupted, the device may keep holding SDA low while it
+//! waits for the controller to clock out the rest of the data. The bus then
+//! hangs until the device is power-cycled.
+//!
+//! [`recover_bus()`] drives SCL and SDA as GPIO pins to clock out up to
+//! nine pulses until the device releases SDA and then issues a STOP
+//! condition. [`Ads1x1x::recover_bus()`] additionally writes the
+//! configuration to the device again.
+//!
+//! A good moment to try a bus recovery is when the register accesses keep
+//! failing. See [`RetryStats::consecutive_failures`](crate::RetryStats).
+//!
+//! Both pins must be configured as open-drain outputs. SDA must be readable
+//! while configured as output.
+
+use crate::{Ads1x1x, Error};
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{ErrorKind, InputPin, OutputPin},
+};
+
+/// Maximum number of clock pulses sent to release SDA.
+const MAX_PULSES: u8 = 9;
+/// Half of the SCL period in microseconds. This corresponds to 100 kHz.
+const HALF_PERIOD_US: u32 = 5;
+
+/// Errors during a bus recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryError {
+    /// Error driving or reading one of the pins.
+    Pin(ErrorKind),
+    /// SDA is still held low after clocking out all pulses.
+    SdaStuckLow,
+}
+
+/// Recovers a hung I²C bus.
+///
+/// Clocks out up to nine pulses on SCL until SDA is released and then
+/// issues a STOP condition.
+///
+/// Returns the number of pulses sent.
+pub fn recover_bus<SCL, SDA, D>(
+    scl: &mut SCL,
+    sda: &mut SDA,
+    delay: &mut D,
+) -> Result<u8, RecoveryError>
+where
+    SCL: OutputPin,
+    SDA: InputPin + OutputPin,
+    D: DelayNs,
+{
+    fn pin<E: embedded_hal::digital::Error>(e: E) -> RecoveryError {
+        RecoveryError::Pin(e.kind())
+    }
+
+    sda.set_high().map_err(pin)?;
+    scl.set_high().map_err(pin)?;
+    delay.delay_us(HALF_PERIOD_US);
+    let mut pulses = 0;
+    while pulses < MAX_PULSES && sda.is_low().map_err(pin)? {
+        scl.set_low().map_err(pin)?;
+        delay.delay_us(HALF_PERIOD_US);
+        scl.set_high().map_err(pin)?;
+        delay.delay_us(HALF_PERIOD_US);
+        pulses += 1;
+    }
+    if sda.is_low().map_err(pin)? {
+        return Err(RecoveryError::SdaStuckLow);
+    }
+
+    // STOP condition: SDA rising while SCL is high.
+    scl.set_low().map_err(pin)?;
+    delay.delay_us(HALF_PERIOD_US);
+    sda.set_low().map_err(pin)?;
+    delay.delay_us(HALF_PERIOD_US);
+    scl.set_high().map_err(pin)?;
+    delay.delay_us(HALF_PERIOD_US);
+    sda.set_high().map_err(pin)?;
+    delay.delay_us(HALF_PERIOD_US);
+    Ok(pulses)
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+{
+    /// Recovers a hung I²C bus and writes the configuration to the device again.
+    ///
+    /// See [`recover_bus()`](crate::recovery::recover_bus) and
+    /// [`resync_config()`](Self::resync_config).
+    pub fn recover_bus<SCL, SDA, D>(
+        &mut self,
+        scl: &mut SCL,
+        sda: &mut SDA,
+        delay: &mut D,
+    ) -> Result<(), Error<E>>
+    where
+        SCL: OutputPin,
+        SDA: InputPin + OutputPin,
+        D: DelayNs,
+    {
+        recover_bus(scl, sda, delay).map_err(Error::BusRecovery)?;
+        self.resync_config()
+    }
+}
@@ -0,0 +1,156 @@
+//! Retry policy for transient I²C bus errors.
+
+use embedded_hal::{
+    delay::DelayNs,
+    i2c::{Error as _, ErrorKind, I2c},
+};
+
+/// Retry policy for transient I²C bus errors.
+///
+/// Register accesses that fail with a retryable error (arbitration loss,
+/// NACK or bus error) are attempted again up to the configured number of
+/// attempts, optionally waiting between attempts.
+///
+/// Retrying the write that triggers a one-shot conversion usually does not
+/// start a second conversion, as writing the OS bit while a conversion is
+/// ongoing has no effect. However, if the first write reached the device but
+/// its acknowledge was lost and the conversion finished before the retry,
+/// for example during the backoff, the retry starts another conversion.
+/// The result read afterwards is then the one of the second conversion.
+///
+/// See [`set_retry_policy()`](crate::Ads1x1x::set_retry_policy). The delay
+/// used to wait between attempts is given to the driver with
+/// [`with_retry_delay()`](crate::Ads1x1x::with_retry_delay).
+///
+/// ```no_run
+/// use ads1x1x::{Ads1x1x, RetryPolicy, TargetAddr};
+/// use linux_embedded_hal::{Delay, I2cdev};
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+/// let mut adc = adc.with_retry_delay(Delay);
+/// adc.set_retry_policy(RetryPolicy::new(3).with_backoff(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+    attempts: u8,
+    backoff_us: u32,
+}
+
+impl Default for RetryPolicy {
+    /// A single attempt without retries.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given maximum number of attempts per
+    /// register access and no waiting between attempts.
+    ///
+    /// A value of `0` is treated as `1`, which means no retries.
+    pub const fn new(attempts: u8) -> Self {
+        RetryPolicy {
+            attempts,
+            backoff_us: 0,
+        }
+    }
+
+    /// Waits the given number of microseconds between attempts.
+    ///
+    /// The delay given with [`with_retry_delay()`](crate::Ads1x1x::with_retry_delay)
+    /// is used. Without it, [`NoDelay`] does not wait at all.
+    pub const fn with_backoff(self, backoff_us: u32) -> Self {
+        RetryPolicy {
+            attempts: self.attempts,
+            backoff_us,
+        }
+    }
+
+    /// Returns the maximum number of attempts per register access.
+    pub const fn attempts(&self) -> u8 {
+        if self.attempts == 0 {
+            1
+        } else {
+            self.attempts
+        }
+    }
+
+    /// Returns the number of microseconds to wait between attempts.
+    pub const fn backoff_us(&self) -> u32 {
+        self.backoff_us
+    }
+}
+
+/// Delay that does not wait.
+///
+/// This is used by the driver until a delay is given with
+/// [`with_retry_delay()`](crate::Ads1x1x::with_retry_delay).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoDelay;
+
+impl DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Statistics on retried register accesses.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryStats {
+    /// Number of additional attempts made.
+    pub retries: u32,
+    /// Number of register accesses that succeeded after being retried.
+    pub recovered: u32,
+    /// Number of register accesses that failed after all attempts or
+    /// with an error that is not retryable.
+    pub failures: u32,
+    /// Number of register accesses that failed since the last successful one.
+    ///
+    /// A high value may indicate a hung bus. See [`recovery`](crate::recovery).
+    pub consecutive_failures: u32,
+}
+
+/// Returns whether an error of this kind may go away when trying again.
+pub(crate) fn is_retryable(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::ArbitrationLoss | ErrorKind::NoAcknowledge(_) | ErrorKind::Bus
+    )
+}
+
+/// Runs the bus operation according to the retry policy.
+pub(crate) fn with_retries<I2C, D, T>(
+    i2c: &mut I2C,
+    policy: &RetryPolicy,
+    stats: &mut RetryStats,
+    delay: &mut D,
+    mut operation: impl FnMut(&mut I2C) -> Result<T, I2C::Error>,
+) -> Result<T, I2C::Error>
+where
+    I2C: I2c,
+    D: DelayNs,
+{
+    let mut attempt = 1;
+    loop {
+        match operation(i2c) {
+            Ok(value) => {
+                if attempt > 1 {
+                    stats.recovered = stats.recovered.saturating_add(1);
+                }
+                stats.consecutive_failures = 0;
+                return Ok(value);
+            }
+            Err(e) if attempt < policy.attempts() && is_retryable(e.kind()) => {
+                attempt += 1;
+                stats.retries = stats.retries.saturating_add(1);
+                if policy.backoff_us > 0 {
+                    delay.delay_us(policy.backoff_us);
+                }
+            }
+            Err(e) => {
+                stats.failures = stats.failures.saturating_add(1);
+                stats.consecutive_failures = stats.consecutive_failures.saturating_add(1);
+                return Err(e);
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Channel rotation in continuous conversion mode.
+//!
+//! In continuous conversion mode a new conversion starts as soon as the
+//! previous one finishes. When the channel is changed, the ongoing
+//! conversion is completed using the previous channel, so the next result
+//! does not belong to the new channel.
+//!
+//! A [`Scanner`] rotates through a sequence of channels and keeps track of
+//! which channel each result belongs to. It is driven by the conversion-ready
+//! pulses on the ALERT/RDY pin (see
+//! [`into_conversion_ready()`](crate::Ads1x1x::into_conversion_ready)):
+//! [`Scanner::on_ready()`] must be called once for every pulse.
+//!
+//! After each channel change, the configured number of results of the new
+//! channel are discarded to let the input settle.
+//!
+//! ```no_run
+//! use ads1x1x::{channel::ChannelSelection, Ads1x1x, TargetAddr};
+//! use embedded_hal::digital::InputPin;
+//! use linux_embedded_hal::{CdevPin, I2cdev};
+//! # fn alert_rdy_pin() -> CdevPin { unimplemented!() }
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+//! let adc = adc.into_conversion_ready().ok().unwrap();
+//! let mut adc = adc.into_continuous().ok().unwrap();
+//! let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+//! let mut scanner = adc.scan(channels, 1).unwrap();
+//! let mut alert_rdy = alert_rdy_pin();
+//! loop {
+//!     // Wait for a ready pulse (active low by default)
+//!     while alert_rdy.is_high().unwrap() {}
+//!     if let Some((channel, value)) = scanner.on_ready().unwrap() {
+//!         println!("{:?}: {}", channel, value);
+//!     }
+//!     while alert_rdy.is_low().unwrap() {}
+//! }
+//! ```
+
+use crate::{
+    alert_rdy, channel::ChannelSelection, conversion, ic, mode, Ads1x1x, Error, NoDelay, Register,
+};
+
+/// Rotation through a fixed sequence of channels in continuous conversion mode.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct Scanner<'a, I2C, IC, CONV, const N: usize, DELAY = NoDelay> {
+    adc: &'a mut Ads1x1x<I2C, IC, CONV, mode::Continuous, alert_rdy::ConversionReady, DELAY>,
+    channels: [ChannelSelection; N],
+    /// Index of the channel selected in the device.
+    selected: usize,
+    /// Channel of the ongoing conversion, if known.
+    converting: Option<ChannelSelection>,
+    /// Number of results of the selected channel to discard.
+    discard: u8,
+    discard_remaining: u8,
+}
+
+impl<I2C, IC, CONV, DELAY, E>
+    Ads1x1x<I2C, IC, CONV, mode::Continuous, alert_rdy::ConversionReady, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::IcFeatures,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Creates a scanner rotating through the channels and selects the first one.
+    ///
+    /// `discard` is the number of results discarded after each channel change.
+    ///
+    /// The ALERT/RDY pin must be used as conversion-ready pin, as the scanner
+    /// is driven by its pulses.
+    ///
+    /// Returns [`Error::InvalidInputData`] if no channel is given and
+    /// [`Error::NotSupported`] if the device cannot measure on one of the channels.
+    pub fn scan<const N: usize>(
+        &mut self,
+        channels: [ChannelSelection; N],
+        discard: u8,
+    ) -> Result<Scanner<'_, I2C, IC, CONV, N, DELAY>, Error<E>> {
+        if N == 0 {
+            return Err(Error::InvalidInputData);
+        }
+        if !channels.iter().all(|channel| Self::supports(*channel)) {
+            return Err(Error::NotSupported);
+        }
+        let mut scanner = Scanner {
+            adc: self,
+            channels,
+            selected: 0,
+            converting: None,
+            discard,
+            discard_remaining: discard,
+        };
+        scanner.select(0)?;
+        Ok(scanner)
+    }
+}
+
+impl<I2C, IC, CONV, DELAY, E, const N: usize> Scanner<'_, I2C, IC, CONV, N, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Handles a conversion-ready pulse.
+    ///
+    /// Reads the result of the conversion that just finished and returns it
+    /// together with the channel it belongs to. Returns `None` if the result
+    /// was discarded.
+    ///
+    /// After the first result of a channel that is not discarded, the next
+    /// channel in the sequence is selected. As the conversion that is
+    /// ongoing at that moment still uses the previous channel, its result
+    /// is returned as well.
+    pub fn on_ready(&mut self) -> Result<Option<(ChannelSelection, i16)>, Error<E>> {
+        let value = self.adc.read()?;
+        let completed = self.converting;
+        // The conversion that just started uses the channel selected now.
+        let selected = self.channels[self.selected];
+        self.converting = Some(selected);
+        let channel = match completed {
+            Some(channel) => channel,
+            None => return Ok(None),
+        };
+        if channel != selected {
+            return Ok(Some((channel, value)));
+        }
+        if self.discard_remaining > 0 {
+            self.discard_remaining -= 1;
+            return Ok(None);
+        }
+        if N > 1 {
+            self.select((self.selected + 1) % N)?;
+        }
+        Ok(Some((channel, value)))
+    }
+
+    /// Returns the channel selected in the device.
+    pub fn selected_channel(&self) -> ChannelSelection {
+        self.channels[self.selected]
+    }
+
+    fn select(&mut self, index: usize) -> Result<(), Error<E>> {
+        let config = self.adc.config.with_mux_bits(self.channels[index]);
+        if self.adc.config != config {
+            self.adc.write_register(Register::CONFIG, config.bits)?;
+            self.adc.config = config;
+            self.discard_remaining = self.discard;
+        }
+        self.selected = index;
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! Pipelined multi-channel sampling in one-shot mode.
+//!
+//! Reading several channels one after the other with `read()` leaves the
+//! ADC idle between the moment a result is ready and the moment the next
+//! conversion is triggered. A [`Sequencer`] reads the result of a channel
+//! and immediately triggers the conversion of the next channel, so that the
+//! ADC is kept busy all the time.
+//!
+//! ```no_run
+//! use ads1x1x::{channel::ChannelSelection, Ads1x1x, TargetAddr};
+//! use linux_embedded_hal::I2cdev;
+//! use nb::block;
+//! use std::time::Instant;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+//! let start = Instant::now();
+//! let channels = [
+//!     ChannelSelection::SingleA0,
+//!     ChannelSelection::SingleA1,
+//!     ChannelSelection::SingleA2,
+//!     ChannelSelection::SingleA3,
+//! ];
+//! let mut sequencer = adc
+//!     .sequence(channels, || start.elapsed().as_micros() as u32)
+//!     .unwrap();
+//! for _ in 0..100 {
+//!     let (channel, value) = block!(sequencer.poll()).unwrap();
+//!     println!("{:?}: {}", channel, value);
+//! }
+//! println!("Throughput: {:?} SPS", sequencer.throughput_sps());
+//! ```
+
+use crate::{
+    alert_rdy, channel::ChannelSelection, conversion, ic, mode, Ads1x1x, BitFlags, Clock, Error,
+    NoDelay, Register,
+};
+
+/// Pipelined sampling of a fixed sequence of channels.
+///
+/// See the [module documentation](self) for details.
+///
+/// When the sequencer is dropped, the conversion of the next channel in the
+/// sequence may still be ongoing. It is read by the next one-shot `read()`
+/// of that channel like any other conversion.
+#[derive(Debug)]
+pub struct Sequencer<
+    'a,
+    I2C,
+    IC,
+    CONV,
+    C,
+    const N: usize,
+    ALERT = alert_rdy::Disabled,
+    DELAY = NoDelay,
+> {
+    adc: &'a mut Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>,
+    channels: [ChannelSelection; N],
+    current: usize,
+    clock: C,
+    started_at: Option<u32>,
+    last_sample_at: u32,
+    samples: u32,
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::IcFeatures,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Creates a sequencer for sampling the channels one after the other.
+    ///
+    /// The clock is only used for reporting the throughput.
+    ///
+    /// Returns [`Error::InvalidInputData`] if no channel is given and
+    /// [`Error::NotSupported`] if the device cannot measure on one of the channels.
+    #[allow(clippy::type_complexity)]
+    pub fn sequence<C: Clock, const N: usize>(
+        &mut self,
+        channels: [ChannelSelection; N],
+        clock: C,
+    ) -> Result<Sequencer<'_, I2C, IC, CONV, C, N, ALERT, DELAY>, Error<E>> {
+        if N == 0 {
+            return Err(Error::InvalidInputData);
+        }
+        if !channels.iter().all(|channel| Self::supports(*channel)) {
+            return Err(Error::NotSupported);
+        }
+        Ok(Sequencer {
+            adc: self,
+            channels,
+            current: 0,
+            clock,
+            started_at: None,
+            last_sample_at: 0,
+            samples: 0,
+        })
+    }
+}
+
+impl<I2C, IC, CONV, C, ALERT, DELAY, E, const N: usize>
+    Sequencer<'_, I2C, IC, CONV, C, N, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+    C: Clock,
+{
+    /// Returns the next result together with the channel it was measured on.
+    ///
+    /// Returns `nb::Error::WouldBlock` while the conversion is in progress.
+    /// A conversion that was ongoing when the sequencer was created is
+    /// waited for and discarded unless it belongs to the first channel.
+    ///
+    /// If triggering the next conversion fails, the result is still returned
+    /// and the conversion is triggered again on the next call.
+    pub fn poll(&mut self) -> nb::Result<(ChannelSelection, i16), Error<E>> {
+        let status = self
+            .adc
+            .read_register(Register::CONFIG)
+            .map_err(nb::Error::Other)?;
+        if self.adc.config_check_due() {
+            self.adc.check_config(status).map_err(nb::Error::Other)?;
+        }
+        if status & BitFlags::OS == 0 {
+            return Err(nb::Error::WouldBlock);
+        }
+        let channel = self.channels[self.current];
+        let config = self.adc.config.with_mux_bits(channel);
+        if !(self.adc.a_conversion_was_started && self.adc.config == config) {
+            self.trigger(channel).map_err(nb::Error::Other)?;
+            return Err(nb::Error::WouldBlock);
+        }
+        let value = self
+            .adc
+            .read_register(Register::CONVERSION)
+            .map_err(nb::Error::Other)?;
+        self.adc.a_conversion_was_started = false;
+        self.last_sample_at = self.clock.now_us();
+        self.samples = self.samples.saturating_add(1);
+        self.current = (self.current + 1) % N;
+        // Keep the ADC busy. Errors are reported on the next call.
+        let _ = self.trigger(self.channels[self.current]);
+        Ok((channel, CONV::convert_measurement(value)))
+    }
+
+    /// Returns the number of results returned so far.
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Returns the aggregate number of results per second over all channels.
+    ///
+    /// This is measured from the first conversion triggered to the last
+    /// result returned. Returns `None` until a result has been returned.
+    pub fn throughput_sps(&self) -> Option<u32> {
+        let elapsed_us = self.last_sample_at.wrapping_sub(self.started_at?);
+        if self.samples == 0 || elapsed_us == 0 {
+            return None;
+        }
+        Some((u64::from(self.samples) * 1_000_000 / u64::from(elapsed_us)) as u32)
+    }
+
+    fn trigger(&mut self, channel: ChannelSelection) -> Result<(), Error<E>> {
+        let config = self.adc.config.with_mux_bits(channel);
+        self.adc.trigger_measurement(&config)?;
+        self.adc.config = config;
+        self.adc.a_conversion_was_started = true;
+        self.adc.conversion_deadline = None;
+        if self.started_at.is_none() {
+            self.started_at = Some(self.clock.now_us());
+        }
+        Ok(())
+    }
+}
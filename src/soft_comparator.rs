@@ -0,0 +1,243 @@
+//! Software comparator for any device and several channels.
+//!
+//! The hardware comparator is only available on ADS1x14 and ADS1x15 devices
+//! and only watches the channel being converted. A [`SoftComparator`]
+//! evaluates conversion results in software following the semantics of
+//! [`ComparatorMode`], [`ComparatorQueue`] and [`ComparatorLatching`], so it
+//! works with every device. A [`ComparatorBank`] keeps independent
+//! thresholds and queues for several channels, for example when sampling
+//! with a [`Sequencer`](crate::sequencer::Sequencer) or a
+//! [`Scanner`](crate::scanner::Scanner).
+//!
+//! ```no_run
+//! use ads1x1x::{
+//!     channel::ChannelSelection,
+//!     soft_comparator::{AlertEvent, ComparatorBank, SoftComparator},
+//!     Ads1x1x, ComparatorQueue, TargetAddr,
+//! };
+//! use linux_embedded_hal::I2cdev;
+//! use nb::block;
+//! use std::time::Instant;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut adc = Ads1x1x::new_ads1015(dev, TargetAddr::default());
+//! let start = Instant::now();
+//! let mut comparators = ComparatorBank::new([
+//!     SoftComparator::new(ChannelSelection::SingleA0, 500, 1500),
+//!     SoftComparator::new(ChannelSelection::SingleA1, -100, 100).with_queue(ComparatorQueue::One),
+//! ]);
+//! let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+//! let mut sequencer = adc
+//!     .sequence(channels, || start.elapsed().as_micros() as u32)
+//!     .unwrap();
+//! loop {
+//!     let (channel, value) = block!(sequencer.poll()).unwrap();
+//!     match comparators.update(channel, value) {
+//!         Some(AlertEvent::Asserted { channel, value }) => println!("{:?} alert: {}", channel, value),
+//!         Some(AlertEvent::Cleared { channel, .. }) => println!("{:?} back to normal", channel),
+//!         None => (),
+//!     }
+//! }
+//! ```
+
+use crate::{channel::ChannelSelection, ComparatorLatching, ComparatorMode, ComparatorQueue};
+
+/// Change of the alert state of a software comparator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertEvent {
+    /// The alert was asserted by the result `value` of `channel`.
+    Asserted {
+        /// Channel watched by the comparator
+        channel: ChannelSelection,
+        /// Conversion result that asserted the alert
+        value: i16,
+    },
+    /// The alert was cleared by the result `value` of `channel`.
+    Cleared {
+        /// Channel watched by the comparator
+        channel: ChannelSelection,
+        /// Conversion result that cleared the alert
+        value: i16,
+    },
+}
+
+/// Software comparator watching the raw results of one channel.
+///
+/// The thresholds are raw conversion results, like for
+/// [`set_low_threshold_raw()`](crate::Ads1x1x::set_low_threshold_raw).
+/// The comparator mode, queue and latching default to those of the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoftComparator {
+    channel: ChannelSelection,
+    low_threshold: i16,
+    high_threshold: i16,
+    mode: ComparatorMode,
+    queue: ComparatorQueue,
+    latching: ComparatorLatching,
+    /// Number of consecutive results exceeding the thresholds.
+    exceeding: u8,
+    asserted: bool,
+}
+
+impl SoftComparator {
+    /// Creates a comparator for the channel with the given raw thresholds.
+    pub const fn new(channel: ChannelSelection, low_threshold: i16, high_threshold: i16) -> Self {
+        SoftComparator {
+            channel,
+            low_threshold,
+            high_threshold,
+            mode: ComparatorMode::Traditional,
+            queue: ComparatorQueue::Four,
+            latching: ComparatorLatching::Nonlatching,
+            exceeding: 0,
+            asserted: false,
+        }
+    }
+
+    /// Sets the comparator mode.
+    pub const fn with_mode(mut self, mode: ComparatorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the number of consecutive results exceeding the thresholds
+    /// needed to assert the alert.
+    pub const fn with_queue(mut self, queue: ComparatorQueue) -> Self {
+        self.queue = queue;
+        self
+    }
+
+    /// Sets whether the alert latches after being asserted.
+    pub const fn with_latching(mut self, latching: ComparatorLatching) -> Self {
+        self.latching = latching;
+        self
+    }
+
+    /// Returns the channel watched.
+    pub const fn channel(&self) -> ChannelSelection {
+        self.channel
+    }
+
+    /// Returns the raw lower and upper thresholds.
+    pub const fn thresholds(&self) -> (i16, i16) {
+        (self.low_threshold, self.high_threshold)
+    }
+
+    /// Sets the raw lower and upper thresholds.
+    ///
+    /// The alert state is kept.
+    pub fn set_thresholds(&mut self, low_threshold: i16, high_threshold: i16) {
+        self.low_threshold = low_threshold;
+        self.high_threshold = high_threshold;
+    }
+
+    /// Returns whether the alert is asserted.
+    pub const fn is_asserted(&self) -> bool {
+        self.asserted
+    }
+
+    /// Clears a latched alert.
+    ///
+    /// This corresponds to reading the conversion result or sending an SMBus
+    /// alert response to the device. The alert is asserted again once the
+    /// results exceed the thresholds for the number of conversions
+    /// configured in the queue.
+    pub fn clear_latch(&mut self) {
+        self.asserted = false;
+        self.exceeding = 0;
+    }
+
+    /// Evaluates a new conversion result.
+    ///
+    /// Returns an event if the alert state changed.
+    pub fn update(&mut self, value: i16) -> Option<AlertEvent> {
+        let channel = self.channel;
+        let exceeds = match self.mode {
+            ComparatorMode::Traditional => value > self.high_threshold,
+            ComparatorMode::Window => value > self.high_threshold || value < self.low_threshold,
+        };
+        if self.asserted {
+            if self.latching == ComparatorLatching::Latching {
+                return None;
+            }
+            let clears = match self.mode {
+                ComparatorMode::Traditional => value < self.low_threshold,
+                ComparatorMode::Window => !exceeds,
+            };
+            if clears {
+                self.clear_latch();
+                return Some(AlertEvent::Cleared { channel, value });
+            }
+            return None;
+        }
+        if !exceeds {
+            self.exceeding = 0;
+            return None;
+        }
+        self.exceeding += 1;
+        if self.exceeding < queue_length(self.queue) {
+            return None;
+        }
+        self.asserted = true;
+        self.exceeding = 0;
+        Some(AlertEvent::Asserted { channel, value })
+    }
+}
+
+/// Independent software comparators for several channels.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComparatorBank<const N: usize> {
+    comparators: [SoftComparator; N],
+}
+
+impl<const N: usize> ComparatorBank<N> {
+    /// Creates a bank from the comparators of each channel.
+    ///
+    /// If several comparators watch the same channel, only the first one is used.
+    pub const fn new(comparators: [SoftComparator; N]) -> Self {
+        ComparatorBank { comparators }
+    }
+
+    /// Evaluates a new conversion result of a channel.
+    ///
+    /// Returns an event if the alert state of the channel changed. Results
+    /// of channels without comparator are ignored.
+    pub fn update(&mut self, channel: ChannelSelection, value: i16) -> Option<AlertEvent> {
+        self.get_mut(channel)?.update(value)
+    }
+
+    /// Returns the comparator of a channel.
+    pub fn get(&self, channel: ChannelSelection) -> Option<&SoftComparator> {
+        self.comparators.iter().find(|c| c.channel == channel)
+    }
+
+    /// Returns the comparator of a channel for modification.
+    pub fn get_mut(&mut self, channel: ChannelSelection) -> Option<&mut SoftComparator> {
+        self.comparators.iter_mut().find(|c| c.channel == channel)
+    }
+
+    /// Returns the channels whose alert is asserted.
+    pub fn asserted(&self) -> impl Iterator<Item = ChannelSelection> + '_ {
+        self.comparators
+            .iter()
+            .filter(|c| c.asserted)
+            .map(|c| c.channel)
+    }
+
+    /// Clears the latched alerts of all channels.
+    pub fn clear_latches(&mut self) {
+        self.comparators
+            .iter_mut()
+            .for_each(SoftComparator::clear_latch);
+    }
+}
+
+const fn queue_length(queue: ComparatorQueue) -> u8 {
+    match queue {
+        ComparatorQueue::One => 1,
+        ComparatorQueue::Two => 2,
+        ComparatorQueue::Four => 4,
+    }
+}
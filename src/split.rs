@@ -0,0 +1,141 @@
+//! Independently owned channel handles.
+//!
+//! A device in one-shot mode can be split into per-channel handles with
+//! [`Ads1x1x::split()`]. The handles share the device and can be handed to
+//! different parts of the application, each of them owning one input.
+//!
+//! Conversions requested through different handles are serialised: while a
+//! conversion requested by one handle has not been read back by it, reads
+//! on the other handles return `nb::Error::WouldBlock`. This prevents a
+//! handle from discarding a conversion triggered by another one.
+//! As a consequence, a handle must keep polling until its read completes.
+//! Dropping a handle releases the device to the other handles.
+//!
+//! ```no_run
+//! use ads1x1x::{channel, Ads1x1x, TargetAddr};
+//! use linux_embedded_hal::I2cdev;
+//! use nb::block;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let adc = Ads1x1x::new_ads1115(dev, TargetAddr::default());
+//! let shared = adc.split();
+//! let mut a0 = shared.channel(channel::SingleA0);
+//! let mut a1 = shared.channel(channel::SingleA1);
+//! let value0 = block!(a0.read()).unwrap();
+//! let value1 = block!(a1.read()).unwrap();
+//! // get the device back once the handles are dropped
+//! drop((a0, a1));
+//! let adc = shared.release();
+//! ```
+
+use crate::{
+    alert_rdy, channel::ChannelSelection, conversion, mode, Ads1x1x, ChannelId, Error, NoDelay,
+};
+use core::{cell::RefCell, marker::PhantomData};
+
+#[derive(Debug)]
+struct SharedState<I2C, IC, CONV, ALERT, DELAY> {
+    adc: Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>,
+    owner: Option<ChannelSelection>,
+}
+
+/// Device in one-shot mode shared between channel handles.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct SharedAdc<I2C, IC, CONV, ALERT = alert_rdy::Disabled, DELAY = NoDelay> {
+    state: RefCell<SharedState<I2C, IC, CONV, ALERT, DELAY>>,
+}
+
+/// Handle owning one input channel of a [`SharedAdc`].
+#[derive(Debug)]
+pub struct ChannelHandle<'a, I2C, IC, CONV, CH, ALERT = alert_rdy::Disabled, DELAY = NoDelay> {
+    shared: &'a SharedAdc<I2C, IC, CONV, ALERT, DELAY>,
+    channel: ChannelSelection,
+    _channel: PhantomData<CH>,
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Splits the device into independently owned channel handles.
+    ///
+    /// The handles are created with [`SharedAdc::channel()`].
+    pub fn split(self) -> SharedAdc<I2C, IC, CONV, ALERT, DELAY> {
+        SharedAdc {
+            state: RefCell::new(SharedState {
+                adc: self,
+                owner: None,
+            }),
+        }
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> SharedAdc<I2C, IC, CONV, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Creates a handle for the specified channel.
+    #[allow(unused_variables)]
+    pub fn channel<CH>(&self, channel: CH) -> ChannelHandle<'_, I2C, IC, CONV, CH, ALERT, DELAY>
+    where
+        CH: ChannelId<Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>>,
+    {
+        ChannelHandle {
+            shared: self,
+            channel: CH::channel_id(),
+            _channel: PhantomData,
+        }
+    }
+
+    /// Returns the device.
+    ///
+    /// The handles borrow the shared device so they must have been dropped.
+    pub fn release(self) -> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY> {
+        self.state.into_inner().adc
+    }
+}
+
+impl<I2C, IC, CONV, CH, ALERT, DELAY, E> ChannelHandle<'_, I2C, IC, CONV, CH, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: conversion::ConvertMeasurement,
+    CH: ChannelId<Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>>,
+{
+    /// Requests a conversion on the channel of this handle.
+    ///
+    /// This behaves like the one-shot [`Ads1x1x::read()`](Ads1x1x#method.read-1)
+    /// but returns `nb::Error::WouldBlock` as well while a conversion
+    /// requested by another handle has not been read back.
+    pub fn read(&mut self) -> nb::Result<i16, Error<E>> {
+        let channel = self.channel;
+        let mut state = self.shared.state.borrow_mut();
+        match state.owner {
+            Some(owner) if owner != channel => return Err(nb::Error::WouldBlock),
+            _ => state.owner = Some(channel),
+        }
+        let result = state.adc.read_selection(channel);
+        if !matches!(result, Err(nb::Error::WouldBlock)) {
+            state.owner = None;
+        }
+        result
+    }
+}
+
+impl<I2C, IC, CONV, CH, ALERT, DELAY> Drop for ChannelHandle<'_, I2C, IC, CONV, CH, ALERT, DELAY> {
+    /// Releases the device if a conversion requested by this handle is pending.
+    ///
+    /// The next read on another handle discards that conversion.
+    fn drop(&mut self) {
+        let mut state = self.shared.state.borrow_mut();
+        if state.owner == Some(self.channel) {
+            state.owner = None;
+        }
+    }
+}
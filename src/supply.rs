@@ -0,0 +1,180 @@
+//! Input voltage validity checks against the supply voltage.
+
+use crate::{channel::ChannelSelection, ic, Ads1x1x, FullScaleRange};
+use core::ops::RangeInclusive;
+
+/// Analog inputs may exceed the supply rails by up to 300mV.
+const SUPPLY_MARGIN_MV: i32 = 300;
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY> {
+    /// Declares the supply voltage (VDD) of the device in millivolts.
+    ///
+    /// Whatever the full-scale range, the device cannot measure input
+    /// voltages above VDD + 0.3V or below GND - 0.3V, so differential
+    /// inputs are limited to ±(VDD + 0.6V). Once the supply voltage
+    /// is known, the driver reports the usable code range and unreachable
+    /// comparator thresholds and flags measurements at the supply limit.
+    ///
+    /// ```no_run
+    /// use ads1x1x::{Ads1x1x, TargetAddr};
+    /// use linux_embedded_hal::I2cdev;
+    ///
+    /// let i2c = I2cdev::new("/dev/i2c-1").unwrap();
+    /// let adc = Ads1x1x::new_ads1115(i2c, TargetAddr::default()).with_supply_millivolts(3300);
+    /// ```
+    pub fn with_supply_millivolts(mut self, millivolts: u16) -> Self {
+        self.supply_mv = Some(millivolts);
+        self
+    }
+
+    /// Returns the supply voltage in millivolts if it was declared.
+    ///
+    /// See [`with_supply_millivolts()`](Self::with_supply_millivolts).
+    pub fn supply_millivolts(&self) -> Option<u16> {
+        self.supply_mv
+    }
+
+    /// Returns whether the full-scale range selected exceeds the input
+    /// voltage range allowed by the supply voltage.
+    ///
+    /// In this case the upper part of the code range cannot be reached.
+    /// For example, this is the case for ±6.144V at a 3.3V supply.
+    /// Always returns `false` if the supply voltage was not declared.
+    pub fn full_scale_range_exceeds_supply(&self) -> bool {
+        match self.supply_mv {
+            Some(supply_mv) => i32::from(self.fsr.millivolts()) > max_input_mv(supply_mv),
+            None => false,
+        }
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    CONV: ic::Resolution,
+{
+    /// Returns the range of conversion results that can be reached for the
+    /// current channel, full-scale range and supply voltage.
+    ///
+    /// Single-ended inputs are limited to `[GND - 0.3V, VDD + 0.3V]` and
+    /// differential inputs to `±(VDD + 0.6V)`. Without a declared supply
+    /// voltage this is the full code range.
+    pub fn usable_code_range(&self) -> RangeInclusive<i16> {
+        usable_code_range(self.supply_mv, self.config.channel(), self.fsr, CONV::BITS)
+    }
+
+    /// Returns whether a result is at or beyond the limit imposed by the
+    /// supply voltage, as opposed to the limit of the full-scale range.
+    pub(crate) fn is_at_supply_limit(&self, raw: i16) -> bool {
+        if self.supply_mv.is_none() {
+            return false;
+        }
+        let usable = self.usable_code_range();
+        let max = max_code(CONV::BITS);
+        (raw >= *usable.end() && i32::from(*usable.end()) < max)
+            || (raw <= *usable.start() && i32::from(*usable.start()) > -max - 1)
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution,
+{
+    /// Returns whether the comparator lower and upper thresholds can be
+    /// reached for the current channel, full-scale range and supply voltage.
+    ///
+    /// A threshold outside of the [usable code range](Self::usable_code_range)
+    /// never trips the comparator. This is fine when parking one side of a
+    /// window at the rail on purpose, but may also mean that the channel or
+    /// full-scale range was changed after setting the thresholds.
+    /// Always returns `(true, true)` if the supply voltage was not declared.
+    pub fn thresholds_reachable(&self) -> (bool, bool) {
+        let usable = self.usable_code_range();
+        let to_code = |register: u16| (register as i16) >> (16 - CONV::BITS);
+        (
+            usable.contains(&to_code(self.low_threshold)),
+            usable.contains(&to_code(self.high_threshold)),
+        )
+    }
+}
+
+const fn max_input_mv(supply_mv: u16) -> i32 {
+    supply_mv as i32 + SUPPLY_MARGIN_MV
+}
+
+const fn max_code(resolution_bits: u8) -> i32 {
+    (1 << (resolution_bits - 1)) - 1
+}
+
+fn usable_code_range(
+    supply_mv: Option<u16>,
+    channel: ChannelSelection,
+    fsr: FullScaleRange,
+    resolution_bits: u8,
+) -> RangeInclusive<i16> {
+    let max = max_code(resolution_bits);
+    let Some(supply_mv) = supply_mv else {
+        return (-max - 1) as i16..=max as i16;
+    };
+    let (lower_mv, upper_mv) = match channel {
+        ChannelSelection::SingleA0
+        | ChannelSelection::SingleA1
+        | ChannelSelection::SingleA2
+        | ChannelSelection::SingleA3 => (-SUPPLY_MARGIN_MV, max_input_mv(supply_mv)),
+        // Each input may be beyond its own rail.
+        _ => {
+            let max_mv = max_input_mv(supply_mv) + SUPPLY_MARGIN_MV;
+            (-max_mv, max_mv)
+        }
+    };
+    // Truncating towards zero keeps both limits within the input range.
+    let to_code = |mv: i32| (mv * (max + 1) / i32::from(fsr.millivolts())).clamp(-max - 1, max);
+    to_code(lower_mv) as i16..=to_code(upper_mv) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_code_range_without_supply() {
+        let range = usable_code_range(
+            None,
+            ChannelSelection::SingleA0,
+            FullScaleRange::Within6_144V,
+            16,
+        );
+        assert_eq!(-32768..=32767, range);
+    }
+
+    #[test]
+    fn upper_range_limited_by_supply() {
+        let range = usable_code_range(
+            Some(3300),
+            ChannelSelection::SingleA0,
+            FullScaleRange::Within6_144V,
+            16,
+        );
+        // 3.6V / 187.5µV and -0.3V / 187.5µV
+        assert_eq!(-1600..=19200, range);
+        let range = usable_code_range(
+            Some(3300),
+            ChannelSelection::DifferentialA0A1,
+            FullScaleRange::Within6_144V,
+            12,
+        );
+        // 3.9V / 3mV
+        assert_eq!(-1300..=1300, range);
+    }
+
+    #[test]
+    fn small_range_is_not_limited_in_differential_mode() {
+        let range = usable_code_range(
+            Some(3300),
+            ChannelSelection::DifferentialA2A3,
+            FullScaleRange::Within2_048V,
+            16,
+        );
+        assert_eq!(-32768..=32767, range);
+    }
+}
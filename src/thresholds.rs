@@ -0,0 +1,199 @@
+//! Comparator threshold helpers.
+
+use crate::{
+    alert_rdy, conversion, conversion::div_round, ic, Ads1x1x, BitFlags as BF, ComparatorMode,
+    Error, Register,
+};
+
+impl<I2C, IC, CONV, MODE, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::Comparator, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
+{
+    /// Sets both raw comparator thresholds.
+    ///
+    /// The upper threshold must be greater than the lower threshold and both
+    /// must be within the limits of [`set_low_threshold_raw()`](Self::set_low_threshold_raw).
+    /// Otherwise [`Error::InvalidInputData`] is returned and nothing is written.
+    ///
+    /// The thresholds are written in an order such that the upper threshold
+    /// stays above the lower threshold at all times, so that no spurious
+    /// alert is asserted in between.
+    pub fn set_thresholds_raw(&mut self, low: i16, high: i16) -> Result<(), Error<E>> {
+        if low >= high {
+            return Err(Error::InvalidInputData);
+        }
+        let low_register = CONV::convert_threshold(low)?;
+        let high_register = CONV::convert_threshold(high)?;
+        // The register values are left-justified so they compare like the codes.
+        if high_register as i16 >= self.high_threshold as i16 {
+            self.write_register(Register::HIGH_TH, high_register)?;
+            self.high_threshold = high_register;
+            self.write_register(Register::LOW_TH, low_register)?;
+            self.low_threshold = low_register;
+        } else {
+            self.write_register(Register::LOW_TH, low_register)?;
+            self.low_threshold = low_register;
+            self.write_register(Register::HIGH_TH, high_register)?;
+            self.high_threshold = high_register;
+        }
+        Ok(())
+    }
+
+    /// Sets the raw trip point and hysteresis of the traditional comparator.
+    ///
+    /// The alert is asserted when the result exceeds `trip_point` and cleared
+    /// when it falls below `trip_point - hysteresis`.
+    ///
+    /// Returns [`Error::InvalidInputData`] if the comparator is in window mode,
+    /// the hysteresis is zero or the thresholds are out of range.
+    /// See [`set_thresholds_raw()`](Self::set_thresholds_raw).
+    pub fn set_trip_point_raw(&mut self, trip_point: i16, hysteresis: u16) -> Result<(), Error<E>> {
+        if self.comparator_mode() != ComparatorMode::Traditional {
+            return Err(Error::InvalidInputData);
+        }
+        let low = i32::from(trip_point) - i32::from(hysteresis);
+        let low = i16::try_from(low).map_err(|_| Error::InvalidInputData)?;
+        self.set_thresholds_raw(low, trip_point)
+    }
+
+    /// Sets the raw center and width of the window comparator.
+    ///
+    /// The alert is asserted when the result leaves
+    /// `[center - width / 2, center - width / 2 + width]`.
+    ///
+    /// Returns [`Error::InvalidInputData`] if the comparator is in traditional
+    /// mode, the width is zero or the thresholds are out of range.
+    /// See [`set_thresholds_raw()`](Self::set_thresholds_raw).
+    pub fn set_window_raw(&mut self, center: i16, width: u16) -> Result<(), Error<E>> {
+        if self.comparator_mode() != ComparatorMode::Window {
+            return Err(Error::InvalidInputData);
+        }
+        let low = i32::from(center) - i32::from(width / 2);
+        let high = low + i32::from(width);
+        let low = i16::try_from(low).map_err(|_| Error::InvalidInputData)?;
+        let high = i16::try_from(high).map_err(|_| Error::InvalidInputData)?;
+        self.set_thresholds_raw(low, high)
+    }
+
+    /// Sets the comparator lower threshold in microvolts.
+    ///
+    /// The voltage is rounded to the nearest code according to the
+    /// full-scale range selected. Note that the threshold is not updated
+    /// when changing the full-scale range afterwards.
+    ///
+    /// Returns [`Error::InvalidInputData`] if the voltage is outside of the
+    /// full-scale range.
+    pub fn set_low_threshold_microvolts(&mut self, microvolts: i32) -> Result<(), Error<E>> {
+        let code = self.microvolts_code(microvolts)?;
+        self.set_low_threshold_raw(code)
+    }
+
+    /// Sets the comparator upper threshold in microvolts.
+    ///
+    /// See [`set_low_threshold_microvolts()`](Self::set_low_threshold_microvolts).
+    pub fn set_high_threshold_microvolts(&mut self, microvolts: i32) -> Result<(), Error<E>> {
+        let code = self.microvolts_code(microvolts)?;
+        self.set_high_threshold_raw(code)
+    }
+
+    /// Sets the trip point and hysteresis of the traditional comparator in
+    /// microvolts.
+    ///
+    /// The voltages are rounded to the nearest code according to the
+    /// full-scale range selected.
+    /// See [`set_trip_point_raw()`](Self::set_trip_point_raw).
+    pub fn set_trip_point_microvolts(
+        &mut self,
+        trip_point: i32,
+        hysteresis: u32,
+    ) -> Result<(), Error<E>> {
+        let trip_point = self.microvolts_code(trip_point)?;
+        let hysteresis = self.microvolts_span(hysteresis)?;
+        self.set_trip_point_raw(trip_point, hysteresis)
+    }
+
+    /// Sets the center and width of the window comparator in microvolts.
+    ///
+    /// The voltages are rounded to the nearest code according to the
+    /// full-scale range selected.
+    /// See [`set_window_raw()`](Self::set_window_raw).
+    pub fn set_window_microvolts(&mut self, center: i32, width: u32) -> Result<(), Error<E>> {
+        let center = self.microvolts_code(center)?;
+        let width = self.microvolts_span(width)?;
+        self.set_window_raw(center, width)
+    }
+
+    fn microvolts_code(&self, microvolts: i32) -> Result<i16, Error<E>> {
+        self.fsr
+            .microvolts_to_code(microvolts, CONV::BITS)
+            .ok_or(Error::InvalidInputData)
+    }
+
+    /// Converts a voltage difference to the nearest number of codes.
+    fn microvolts_span(&self, microvolts: u32) -> Result<u16, Error<E>> {
+        let picovolts = i64::from(microvolts) * 1_000_000;
+        let codes = div_round(picovolts, self.fsr.lsb_picovolts(CONV::BITS) as i64);
+        u16::try_from(codes).map_err(|_| Error::InvalidInputData)
+    }
+
+    fn comparator_mode(&self) -> ComparatorMode {
+        if self.config.is_high(BF::COMP_MODE) {
+            ComparatorMode::Window
+        } else {
+            ComparatorMode::Traditional
+        }
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
+{
+    /// Reads the raw comparator thresholds from the device.
+    ///
+    /// Returns the lower and upper thresholds, within `[2047..-2048]` for
+    /// 12-bit devices (`ADS101x`) and within `[32767..-32768]` for 16-bit
+    /// devices (`ADS111x`). The voltages can be calculated with
+    /// [`FullScaleRange::code_to_microvolts()`](crate::FullScaleRange::code_to_microvolts).
+    ///
+    /// This allows finding out which thresholds are armed, for example after
+    /// a warm reboot of the controller. The thresholds kept by the driver are
+    /// not changed. See [`adopt_device_thresholds()`](Self::adopt_device_thresholds).
+    pub fn read_thresholds_raw(&mut self) -> Result<(i16, i16), Error<E>> {
+        let low_threshold = self.read_register(Register::LOW_TH)?;
+        let high_threshold = self.read_register(Register::HIGH_TH)?;
+        Ok((
+            CONV::convert_threshold_back(low_threshold),
+            CONV::convert_threshold_back(high_threshold),
+        ))
+    }
+
+    /// Like [`read_thresholds_raw()`](Self::read_thresholds_raw) but returns
+    /// the thresholds in microvolts according to the full-scale range selected.
+    pub fn read_thresholds_microvolts(&mut self) -> Result<(i32, i32), Error<E>> {
+        let (low, high) = self.read_thresholds_raw()?;
+        Ok((
+            self.fsr.code_to_microvolts(low, CONV::BITS),
+            self.fsr.code_to_microvolts(high, CONV::BITS),
+        ))
+    }
+
+    /// Takes over the comparator thresholds configured in the device.
+    ///
+    /// The thresholds are read from the device and written again when
+    /// restoring the configuration with [`resync_config()`](Self::resync_config),
+    /// for example after a warm reboot of the controller.
+    pub fn adopt_device_thresholds(&mut self) -> Result<(), Error<E>> {
+        let low_threshold = self.read_register(Register::LOW_TH)?;
+        let high_threshold = self.read_register(Register::HIGH_TH)?;
+        self.low_threshold = low_threshold;
+        self.high_threshold = high_threshold;
+        Ok(())
+    }
+}
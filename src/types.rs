@@ -1,19 +1,69 @@
 //! Type definitions.
 
-use core::marker::PhantomData;
+use crate::{channel::ChannelSelection, recovery::RecoveryError, NoDelay, RetryPolicy, RetryStats};
+use core::{fmt, marker::PhantomData};
 
 /// Errors in this crate
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error<E> {
     /// I²C bus error
     I2C(E),
     /// Invalid input data provided
     InvalidInputData,
+    /// The requested feature is not supported by the device
+    NotSupported,
+    /// The operation did not complete in time
+    Timeout,
+    /// The configuration read back from the device does not match the expected one
+    ConfigMismatch {
+        /// Expected CONFIG register value
+        expected: u16,
+        /// CONFIG register value read from the device
+        actual: u16,
+    },
+    /// The device was reset to its default configuration, e.g. after a brown-out
+    DeviceReset,
+    /// The I²C bus could not be recovered
+    BusRecovery(RecoveryError),
+}
+
+impl<E> Error<E>
+where
+    E: embedded_hal::i2c::Error,
+{
+    /// Returns the kind of the I²C bus error, if this is one.
+    pub fn i2c_error_kind(&self) -> Option<embedded_hal::i2c::ErrorKind> {
+        match self {
+            Error::I2C(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::I2C(e) => write!(f, "I²C bus error: {:?}", e),
+            Error::InvalidInputData => f.write_str("invalid input data"),
+            Error::NotSupported => f.write_str("feature not supported by the device"),
+            Error::Timeout => f.write_str("operation timed out"),
+            Error::ConfigMismatch { expected, actual } => write!(
+                f,
+                "configuration mismatch: expected {:#06x}, read {:#06x}",
+                expected, actual
+            ),
+            Error::DeviceReset => f.write_str("device was reset"),
+            Error::BusRecovery(e) => write!(f, "bus recovery failed: {:?}", e),
+        }
+    }
 }
 
+impl<E: fmt::Debug> core::error::Error for Error<E> {}
+
 /// Error type for mode changes.
 ///
 /// This allows to retrieve the unchanged device in case of an error.
+#[derive(Debug)]
 pub enum ModeChangeError<E, DEV> {
     /// I²C bus error while changing mode.
     ///
@@ -22,12 +72,72 @@ pub enum ModeChangeError<E, DEV> {
     I2C(E, DEV),
 }
 
+impl<E, DEV> ModeChangeError<E, DEV> {
+    /// Returns the error that happened and the device with the mode unchanged.
+    pub fn into_parts(self) -> (E, DEV) {
+        match self {
+            ModeChangeError::I2C(e, dev) => (e, dev),
+        }
+    }
+}
+
+impl<E: fmt::Debug, DEV> fmt::Display for ModeChangeError<E, DEV> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModeChangeError::I2C(e, _) => write!(f, "I²C bus error while changing mode: {:?}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug, DEV: fmt::Debug> core::error::Error for ModeChangeError<E, DEV> {}
+
+/// Device variant.
+///
+/// This allows selecting the device at runtime. See [`AnyAds1x1x`](crate::AnyAds1x1x).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum Variant {
+    /// ADS1013: 12-bit, 1 channel.
+    Ads1013,
+    /// ADS1014: 12-bit, 1 channel, comparator and PGA.
+    Ads1014,
+    /// ADS1015: 12-bit, 4 channels, comparator and PGA.
+    Ads1015,
+    /// ADS1113: 16-bit, 1 channel.
+    Ads1113,
+    /// ADS1114: 16-bit, 1 channel, comparator and PGA.
+    Ads1114,
+    /// ADS1115: 16-bit, 4 channels, comparator and PGA.
+    Ads1115,
+}
+
+impl Variant {
+    /// Returns the number of bits of a conversion result (12 or 16).
+    pub const fn resolution_bits(self) -> u8 {
+        match self {
+            Self::Ads1013 | Self::Ads1014 | Self::Ads1015 => 12,
+            Self::Ads1113 | Self::Ads1114 | Self::Ads1115 => 16,
+        }
+    }
+
+    /// Returns whether the device has an input multiplexer.
+    pub const fn has_multiplexer(self) -> bool {
+        matches!(self, Self::Ads1015 | Self::Ads1115)
+    }
+
+    /// Returns whether the device has a programmable gain amplifier and a comparator.
+    pub const fn has_comparator(self) -> bool {
+        !matches!(self, Self::Ads1013 | Self::Ads1113)
+    }
+}
+
 /// Mode marker types
 pub mod mode {
     /// One-shot operating mode / power-down state (default)
+    #[derive(Debug)]
     pub struct OneShot(());
 
     /// Continuous conversion mode
+    #[derive(Debug)]
     pub struct Continuous(());
 }
 
@@ -73,6 +183,103 @@ pub enum DataRate16Bit {
     Sps860,
 }
 
+/// Data rate for any device.
+///
+/// This allows handling the data rate at runtime. See [`DynamicAdc`](crate::DynamicAdc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataRate {
+    /// Data rate for ADS101x.
+    Rate12Bit(DataRate12Bit),
+    /// Data rate for ADS111x.
+    Rate16Bit(DataRate16Bit),
+}
+
+impl From<DataRate12Bit> for DataRate {
+    fn from(rate: DataRate12Bit) -> Self {
+        DataRate::Rate12Bit(rate)
+    }
+}
+
+impl From<DataRate16Bit> for DataRate {
+    fn from(rate: DataRate16Bit) -> Self {
+        DataRate::Rate16Bit(rate)
+    }
+}
+
+impl DataRate {
+    pub(crate) const fn from_bits(bits: u16, resolution_bits: u8) -> Self {
+        if resolution_bits == 12 {
+            DataRate::Rate12Bit(DataRate12Bit::from_bits(bits))
+        } else {
+            DataRate::Rate16Bit(DataRate16Bit::from_bits(bits))
+        }
+    }
+
+    /// Returns the number of samples per second.
+    pub const fn samples_per_second(self) -> u16 {
+        match self {
+            DataRate::Rate12Bit(rate) => rate.samples_per_second(),
+            DataRate::Rate16Bit(rate) => rate.samples_per_second(),
+        }
+    }
+}
+
+impl DataRate12Bit {
+    /// Returns the number of samples per second.
+    pub const fn samples_per_second(self) -> u16 {
+        match self {
+            Self::Sps128 => 128,
+            Self::Sps250 => 250,
+            Self::Sps490 => 490,
+            Self::Sps920 => 920,
+            Self::Sps1600 => 1600,
+            Self::Sps2400 => 2400,
+            Self::Sps3300 => 3300,
+        }
+    }
+
+    pub(crate) const fn from_bits(bits: u16) -> Self {
+        match bits {
+            0 => Self::Sps128,
+            1 => Self::Sps250,
+            2 => Self::Sps490,
+            3 => Self::Sps920,
+            4 => Self::Sps1600,
+            5 => Self::Sps2400,
+            _ => Self::Sps3300,
+        }
+    }
+}
+
+impl DataRate16Bit {
+    /// Returns the number of samples per second.
+    pub const fn samples_per_second(self) -> u16 {
+        match self {
+            Self::Sps8 => 8,
+            Self::Sps16 => 16,
+            Self::Sps32 => 32,
+            Self::Sps64 => 64,
+            Self::Sps128 => 128,
+            Self::Sps250 => 250,
+            Self::Sps475 => 475,
+            Self::Sps860 => 860,
+        }
+    }
+
+    pub(crate) const fn from_bits(bits: u16) -> Self {
+        match bits {
+            0 => Self::Sps8,
+            1 => Self::Sps16,
+            2 => Self::Sps32,
+            3 => Self::Sps64,
+            4 => Self::Sps128,
+            5 => Self::Sps250,
+            6 => Self::Sps475,
+            _ => Self::Sps860,
+        }
+    }
+}
+
 /// Comparator mode (only for ADS1x14, ADS1x15).
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum ComparatorMode {
@@ -120,6 +327,8 @@ pub enum ComparatorLatching {
     /// read by the controller or an appropriate SMBus alert response is sent by
     /// the controller. The device responds with its address, and it is the
     /// lowest address currently asserting the ALERT/RDY bus line.
+    ///
+    /// See [`alert_response()`](crate::alert::alert_response).
     Latching,
 }
 
@@ -160,6 +369,31 @@ pub enum FullScaleRange {
     Within0_256V,
 }
 
+impl FullScaleRange {
+    /// Returns the full-scale voltage in millivolts.
+    pub const fn millivolts(self) -> u16 {
+        match self {
+            Self::Within6_144V => 6144,
+            Self::Within4_096V => 4096,
+            Self::Within2_048V => 2048,
+            Self::Within1_024V => 1024,
+            Self::Within0_512V => 512,
+            Self::Within0_256V => 256,
+        }
+    }
+
+    pub(crate) const fn from_bits(bits: u16) -> Self {
+        match bits {
+            0 => Self::Within6_144V,
+            1 => Self::Within4_096V,
+            2 => Self::Within2_048V,
+            3 => Self::Within1_024V,
+            4 => Self::Within0_512V,
+            _ => Self::Within0_256V,
+        }
+    }
+}
+
 /// A target address.
 ///
 /// See [Table 4 in the datasheet](https://www.ti.com/lit/ds/symlink/ads1115.pdf#%5B%7B%22num%22%3A716%2C%22gen%22%3A0%7D%2C%7B%22name%22%3A%22XYZ%22%7D%2C0%2C602.2%2C0%5D).
@@ -181,6 +415,9 @@ pub enum TargetAddr {
 }
 
 impl TargetAddr {
+    /// All target addresses.
+    pub const ALL: [TargetAddr; 4] = [Self::Gnd, Self::Vdd, Self::Sda, Self::Scl];
+
     pub(crate) const fn bits(self) -> u8 {
         match self {
             Self::Gnd => 0b1001000,
@@ -189,6 +426,16 @@ impl TargetAddr {
             Self::Scl => 0b1001011,
         }
     }
+
+    pub(crate) const fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0b1001000 => Some(Self::Gnd),
+            0b1001001 => Some(Self::Vdd),
+            0b1001010 => Some(Self::Sda),
+            0b1001011 => Some(Self::Scl),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -214,23 +461,176 @@ impl Config {
     }
 }
 
+impl Config {
+    const DR_SHIFT: u16 = 5;
+    const DR_MASK: u16 = 0b111 << Self::DR_SHIFT;
+    const PGA_SHIFT: u16 = 9;
+    const PGA_MASK: u16 = 0b111 << Self::PGA_SHIFT;
+
+    pub(crate) fn data_rate_bits(&self) -> u16 {
+        (self.bits & Self::DR_MASK) >> Self::DR_SHIFT
+    }
+
+    pub(crate) fn with_data_rate_bits(&self, bits: u16) -> Self {
+        Config {
+            bits: (self.bits & !Self::DR_MASK) | ((bits << Self::DR_SHIFT) & Self::DR_MASK),
+        }
+    }
+
+    pub(crate) fn full_scale_range(&self) -> FullScaleRange {
+        FullScaleRange::from_bits((self.bits & Self::PGA_MASK) >> Self::PGA_SHIFT)
+    }
+
+    pub(crate) fn with_full_scale_range(&self, range: FullScaleRange) -> Self {
+        Config {
+            bits: (self.bits & !Self::PGA_MASK) | ((range as u16) << Self::PGA_SHIFT),
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config { bits: 0x8583 }
     }
 }
 
+/// Default LOW_TH register value.
+pub(crate) const DEFAULT_LOW_THRESHOLD: u16 = 0x8000;
+/// Default HIGH_TH register value.
+pub(crate) const DEFAULT_HIGH_THRESHOLD: u16 = 0x7FFF;
+
+/// Policy for verifying the device configuration.
+///
+/// If the device browns out, it silently returns to its default
+/// configuration. The watchdog reads the CONFIG register back and compares
+/// it with the configuration set through the driver. If they differ, the
+/// full configuration including the comparator thresholds is written again
+/// and the event is reported with [`Error::DeviceReset`] or
+/// [`Error::ConfigMismatch`].
+///
+/// See [`set_config_watchdog()`](Ads1x1x::set_config_watchdog).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigWatchdog {
+    /// Do not verify the configuration automatically (default)
+    #[default]
+    Disabled,
+    /// Verify the configuration on each read
+    ///
+    /// In one-shot mode the CONFIG register is read anyway while polling
+    /// so this does not cause additional bus traffic.
+    EveryRead,
+    /// Verify the configuration every N reads
+    EveryNReads(u16),
+}
+
+/// Function of the ALERT/RDY pin (only for ADS1x14, ADS1x15).
+///
+/// See [`read_alert_rdy_function()`](Ads1x1x::read_alert_rdy_function).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertRdyFunction {
+    /// Comparator disabled, pin in high-impedance state (default)
+    #[default]
+    Disabled,
+    /// Pin driven by the comparator
+    Comparator,
+    /// Pin used as conversion-ready signal
+    ConversionReady,
+}
+
+/// ALERT/RDY pin function marker types
+pub mod alert_rdy {
+    /// Comparator disabled, ALERT/RDY pin in high-impedance state (default)
+    #[derive(Debug)]
+    pub struct Disabled(());
+
+    /// ALERT/RDY pin driven by the comparator
+    #[derive(Debug)]
+    pub struct Comparator(());
+
+    /// ALERT/RDY pin used as conversion-ready signal
+    #[derive(Debug)]
+    pub struct ConversionReady(());
+}
+
 /// ADS1x1x ADC driver
 #[derive(Debug, Default)]
-pub struct Ads1x1x<I2C, IC, CONV, MODE> {
+pub struct Ads1x1x<I2C, IC, CONV, MODE, ALERT = alert_rdy::Disabled, DELAY = NoDelay> {
     pub(crate) i2c: I2C,
     pub(crate) address: u8,
     pub(crate) config: Config,
     pub(crate) fsr: FullScaleRange,
+    pub(crate) low_threshold: u16,
+    pub(crate) high_threshold: u16,
+    pub(crate) watchdog: ConfigWatchdog,
+    pub(crate) reads_since_check: u16,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) retry_stats: RetryStats,
+    pub(crate) delay: DELAY,
+    pub(crate) pointer_caching: bool,
+    pub(crate) pointer: Option<u8>,
+    pub(crate) conversion_deadline: Option<u32>,
+    pub(crate) supply_mv: Option<u16>,
     pub(crate) a_conversion_was_started: bool,
+    /// Channel to return to if a channel change in continuous mode is abandoned.
+    pub(crate) channel_switch: Option<ChannelSelection>,
     pub(crate) _conv: PhantomData<CONV>,
     pub(crate) _ic: PhantomData<IC>,
     pub(crate) _mode: PhantomData<MODE>,
+    pub(crate) _alert: PhantomData<ALERT>,
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY> {
+    pub(crate) fn into_mode<NEW>(
+        self,
+        a_conversion_was_started: bool,
+    ) -> Ads1x1x<I2C, IC, CONV, NEW, ALERT, DELAY> {
+        let mut dev = self.into_state(a_conversion_was_started, None, |delay| delay);
+        dev.channel_switch = None;
+        dev
+    }
+
+    pub(crate) fn into_alert_rdy<NEW>(self) -> Ads1x1x<I2C, IC, CONV, MODE, NEW, DELAY> {
+        let a_conversion_was_started = self.a_conversion_was_started;
+        let conversion_deadline = self.conversion_deadline;
+        self.into_state(a_conversion_was_started, conversion_deadline, |delay| delay)
+    }
+
+    pub(crate) fn into_delay<NEW>(self, delay: NEW) -> Ads1x1x<I2C, IC, CONV, MODE, ALERT, NEW> {
+        let a_conversion_was_started = self.a_conversion_was_started;
+        let conversion_deadline = self.conversion_deadline;
+        self.into_state(a_conversion_was_started, conversion_deadline, |_| delay)
+    }
+
+    fn into_state<NEWMODE, NEWALERT, NEWDELAY>(
+        self,
+        a_conversion_was_started: bool,
+        conversion_deadline: Option<u32>,
+        delay: impl FnOnce(DELAY) -> NEWDELAY,
+    ) -> Ads1x1x<I2C, IC, CONV, NEWMODE, NEWALERT, NEWDELAY> {
+        Ads1x1x {
+            i2c: self.i2c,
+            address: self.address,
+            config: self.config,
+            fsr: self.fsr,
+            low_threshold: self.low_threshold,
+            high_threshold: self.high_threshold,
+            watchdog: self.watchdog,
+            reads_since_check: self.reads_since_check,
+            retry: self.retry,
+            retry_stats: self.retry_stats,
+            delay: delay(self.delay),
+            pointer_caching: self.pointer_caching,
+            pointer: self.pointer,
+            conversion_deadline,
+            supply_mv: self.supply_mv,
+            a_conversion_was_started,
+            channel_switch: self.channel_switch,
+            _conv: PhantomData,
+            _ic: PhantomData,
+            _mode: PhantomData,
+            _alert: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +650,14 @@ mod tests {
         assert_eq!(0b100_1011, TargetAddr::Scl.bits());
     }
 
+    #[test]
+    fn target_addr_from_bits() {
+        for address in TargetAddr::ALL {
+            assert_eq!(Some(address), TargetAddr::from_bits(address.bits()));
+        }
+        assert_eq!(None, TargetAddr::from_bits(0b100_1100));
+    }
+
     #[test]
     fn default_full_scale_range() {
         assert_eq!(FullScaleRange::Within2_048V, FullScaleRange::default());
@@ -0,0 +1,181 @@
+//! Typed physical units with [`uom`].
+
+use crate::{
+    alert_rdy, conversion, ic, mode, Ads1x1x, ChannelId, Error, FullScaleRange, Measurement,
+};
+use uom::si::{
+    electric_potential::{millivolt, volt},
+    f32::ElectricPotential,
+};
+
+const PICOVOLTS_PER_VOLT: f32 = 1e12;
+
+impl FullScaleRange {
+    /// Returns the full-scale voltage.
+    pub fn full_scale(self) -> ElectricPotential {
+        ElectricPotential::new::<millivolt>(f32::from(self.millivolts()))
+    }
+
+    /// Returns the size of an LSB for results of the given resolution (12 or 16 bits).
+    pub fn lsb(self, resolution_bits: u8) -> ElectricPotential {
+        ElectricPotential::new::<volt>(
+            self.lsb_picovolts(resolution_bits) as f32 / PICOVOLTS_PER_VOLT,
+        )
+    }
+
+    /// Converts a result of the given resolution to a voltage.
+    pub fn code_to_voltage(self, code: i16, resolution_bits: u8) -> ElectricPotential {
+        let picovolts = f32::from(code) * self.lsb_picovolts(resolution_bits) as f32;
+        ElectricPotential::new::<volt>(picovolts / PICOVOLTS_PER_VOLT)
+    }
+
+    /// Converts a voltage to the nearest code of the given resolution.
+    ///
+    /// Returns `None` if the voltage is outside of the full-scale range.
+    pub fn voltage_to_code(self, voltage: ElectricPotential, resolution_bits: u8) -> Option<i16> {
+        let code = (voltage / self.lsb(resolution_bits)).value;
+        let max = if resolution_bits == 12 {
+            2047.0
+        } else {
+            32767.0
+        };
+        // Negated comparison so that NaN is rejected as well.
+        if !(code > -max - 1.5 && code < max + 0.5) {
+            return None;
+        }
+        let rounded = if code >= 0.0 { code + 0.5 } else { code - 0.5 };
+        Some(rounded as i16)
+    }
+}
+
+impl Measurement {
+    /// Returns the input voltage.
+    pub fn voltage(&self) -> ElectricPotential {
+        self.full_scale_range()
+            .code_to_voltage(self.raw(), self.resolution_bits())
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::OneShot, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Like [`read()`](Self::read) but returns the input voltage according
+    /// to the full-scale range selected.
+    pub fn read_voltage<CH: ChannelId<Self>>(
+        &mut self,
+        channel: CH,
+    ) -> nb::Result<ElectricPotential, Error<E>> {
+        let raw = self.read(channel)?;
+        Ok(self.fsr.code_to_voltage(raw, CONV::BITS))
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, mode::Continuous, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    CONV: ic::Resolution + conversion::ConvertMeasurement,
+{
+    /// Like [`read()`](Self::read) but returns the input voltage according
+    /// to the full-scale range selected.
+    pub fn read_voltage(&mut self) -> Result<ElectricPotential, Error<E>> {
+        let raw = self.read()?;
+        Ok(self.fsr.code_to_voltage(raw, CONV::BITS))
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, ALERT, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
+{
+    /// Like [`read_thresholds_raw()`](Self::read_thresholds_raw) but returns
+    /// the voltages according to the full-scale range selected.
+    pub fn read_thresholds(&mut self) -> Result<(ElectricPotential, ElectricPotential), Error<E>> {
+        let (low, high) = self.read_thresholds_raw()?;
+        Ok((
+            self.fsr.code_to_voltage(low, CONV::BITS),
+            self.fsr.code_to_voltage(high, CONV::BITS),
+        ))
+    }
+}
+
+impl<I2C, IC, CONV, MODE, DELAY, E> Ads1x1x<I2C, IC, CONV, MODE, alert_rdy::Comparator, DELAY>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    DELAY: embedded_hal::delay::DelayNs,
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution + conversion::ConvertThreshold<E>,
+{
+    /// Sets the comparator lower threshold.
+    ///
+    /// The voltage is rounded to the nearest code according to the
+    /// full-scale range selected. Note that the threshold is not updated
+    /// when changing the full-scale range afterwards.
+    ///
+    /// Returns [`Error::InvalidInputData`] if the voltage is outside of the
+    /// full-scale range.
+    pub fn set_low_threshold(&mut self, voltage: ElectricPotential) -> Result<(), Error<E>> {
+        let code = self.threshold_code(voltage)?;
+        self.set_low_threshold_raw(code)
+    }
+
+    /// Sets the comparator upper threshold.
+    ///
+    /// See [`set_low_threshold()`](Self::set_low_threshold).
+    pub fn set_high_threshold(&mut self, voltage: ElectricPotential) -> Result<(), Error<E>> {
+        let code = self.threshold_code(voltage)?;
+        self.set_high_threshold_raw(code)
+    }
+
+    /// Sets the trip point and hysteresis of the traditional comparator.
+    ///
+    /// The voltages are rounded to the nearest code according to the
+    /// full-scale range selected.
+    /// See [`set_trip_point_raw()`](Self::set_trip_point_raw).
+    pub fn set_trip_point(
+        &mut self,
+        trip_point: ElectricPotential,
+        hysteresis: ElectricPotential,
+    ) -> Result<(), Error<E>> {
+        let trip_point = self.threshold_code(trip_point)?;
+        let hysteresis = self.span_code(hysteresis)?;
+        self.set_trip_point_raw(trip_point, hysteresis)
+    }
+
+    /// Sets the center and width of the window comparator.
+    ///
+    /// The voltages are rounded to the nearest code according to the
+    /// full-scale range selected.
+    /// See [`set_window_raw()`](Self::set_window_raw).
+    pub fn set_window(
+        &mut self,
+        center: ElectricPotential,
+        width: ElectricPotential,
+    ) -> Result<(), Error<E>> {
+        let center = self.threshold_code(center)?;
+        let width = self.span_code(width)?;
+        self.set_window_raw(center, width)
+    }
+
+    fn threshold_code(&self, voltage: ElectricPotential) -> Result<i16, Error<E>> {
+        self.fsr
+            .voltage_to_code(voltage, CONV::BITS)
+            .ok_or(Error::InvalidInputData)
+    }
+
+    /// Converts a voltage difference to the nearest number of codes.
+    fn span_code(&self, voltage: ElectricPotential) -> Result<u16, Error<E>> {
+        let codes = (voltage / self.fsr.lsb(CONV::BITS)).value;
+        // Negated comparison so that NaN is rejected as well.
+        if !(codes > -0.5 && codes < f32::from(u16::MAX) + 0.5) {
+            return Err(Error::InvalidInputData);
+        }
+        Ok((codes + 0.5) as u16)
+    }
+}
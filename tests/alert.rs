@@ -0,0 +1,84 @@
+use ads1x1x::{
+    alert::{alert_response, service_alerts, Responder, ALERT_RESPONSE_ADDRESS as ARA},
+    TargetAddr,
+};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+mod common;
+use crate::common::{destroy_ads1115, new_ads1115};
+
+fn no_alert() -> I2cTrans {
+    I2cTrans::read(ARA, vec![0]).with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+}
+
+#[test]
+fn decodes_responding_address() {
+    let transactions = [
+        I2cTrans::read(ARA, vec![0x90]),
+        I2cTrans::read(ARA, vec![0x93]),
+        I2cTrans::read(ARA, vec![0x20]),
+        no_alert(),
+    ];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(
+        Ok(Some(Responder::Ads1x1x(TargetAddr::Gnd))),
+        alert_response(&mut i2c)
+    );
+    assert_eq!(
+        Ok(Some(Responder::Ads1x1x(TargetAddr::Vdd))),
+        alert_response(&mut i2c)
+    );
+    assert_eq!(Ok(Some(Responder::Other(0x10))), alert_response(&mut i2c));
+    assert_eq!(Ok(None), alert_response(&mut i2c));
+    i2c.done();
+}
+
+#[test]
+fn returns_other_bus_errors() {
+    let transactions = [I2cTrans::read(ARA, vec![0]).with_error(ErrorKind::ArbitrationLoss)];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(Err(ErrorKind::ArbitrationLoss), alert_response(&mut i2c));
+    i2c.done();
+}
+
+#[test]
+fn services_all_asserted_devices() {
+    let transactions = [
+        I2cTrans::read(ARA, vec![0x90]),
+        I2cTrans::read(ARA, vec![0x96]),
+        no_alert(),
+    ];
+    let mut i2c = I2cMock::new(&transactions);
+    let mut responders = Vec::new();
+    let count = service_alerts(&mut i2c, |responder| responders.push(responder)).unwrap();
+    assert_eq!(2, count);
+    assert_eq!(
+        vec![
+            Responder::Ads1x1x(TargetAddr::Gnd),
+            Responder::Ads1x1x(TargetAddr::Scl)
+        ],
+        responders
+    );
+    i2c.done();
+}
+
+#[test]
+fn stops_servicing_after_limit() {
+    let transactions = vec![I2cTrans::read(ARA, vec![0x92]); 8];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(Ok(8), service_alerts(&mut i2c, |_| ()));
+    i2c.done();
+}
+
+#[test]
+fn device_can_send_alert_response() {
+    let transactions = [I2cTrans::read(ARA, vec![0x94]), no_alert()];
+    let mut dev = new_ads1115(&transactions);
+    assert_eq!(
+        Ok(Some(Responder::Ads1x1x(TargetAddr::Sda))),
+        dev.alert_response()
+    );
+    assert_eq!(Ok(None), dev.alert_response());
+    destroy_ads1115(dev);
+}
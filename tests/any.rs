@@ -0,0 +1,100 @@
+use ads1x1x::{channel::ChannelSelection, AnyAds1x1x, DynamicAdc, Error, TargetAddr, Variant};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use nb::block;
+
+mod common;
+use crate::common::{BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR};
+
+fn new(variant: Variant, transactions: &[I2cTrans]) -> AnyAds1x1x<I2cMock> {
+    AnyAds1x1x::new(I2cMock::new(transactions), TargetAddr::default(), variant)
+}
+
+#[test]
+fn can_create_all_variants() {
+    for variant in [
+        Variant::Ads1013,
+        Variant::Ads1014,
+        Variant::Ads1015,
+        Variant::Ads1113,
+        Variant::Ads1114,
+        Variant::Ads1115,
+    ] {
+        let dev = new(variant, &[]);
+        assert_eq!(variant, dev.variant());
+        assert_eq!(variant.resolution_bits(), dev.resolution_bits());
+        assert!(!dev.is_continuous());
+        dev.destroy().done();
+    }
+}
+
+macro_rules! normalized_test {
+    ($name:ident, $variant:ident, $msb:expr, $lsb:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let default_config = Config::default();
+            let config = Config::default().with_high(BF::MUX2);
+            let transactions = [
+                I2cTrans::write_read(
+                    DEV_ADDR,
+                    vec![Register::CONFIG],
+                    vec![default_config.msb(), default_config.lsb()],
+                ),
+                I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+                I2cTrans::write_read(
+                    DEV_ADDR,
+                    vec![Register::CONFIG],
+                    vec![config.msb(), config.lsb()],
+                ),
+                I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![$msb, $lsb]),
+            ];
+            let mut dev = new(Variant::$variant, &transactions);
+            let value = block!(dev.read_normalized(ChannelSelection::SingleA0)).unwrap();
+            assert_eq!($expected, value);
+            dev.destroy().done();
+        }
+    };
+}
+
+normalized_test!(read_normalized_12bit, Ads1015, 0x7F, 0xF0, 0x7FF0);
+normalized_test!(read_normalized_16bit, Ads1115, 0x7F, 0xFF, 0x7FFF);
+
+#[test]
+fn reports_unsupported_channel() {
+    let mut dev = new(Variant::Ads1014, &[]);
+    assert!(!dev.supports_channel(ChannelSelection::SingleA0));
+    assert!(matches!(
+        dev.read_normalized(ChannelSelection::SingleA0),
+        Err(nb::Error::Other(Error::NotSupported))
+    ));
+    dev.destroy().done();
+}
+
+#[test]
+fn can_change_mode() {
+    let config_cont = Config::default().with_low(BF::OP_MODE);
+    let config_os = Config::default();
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_cont.msb(), config_cont.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+    ];
+    let dev = new(Variant::Ads1113, &transactions);
+    let dev = dev.into_continuous().ok().unwrap();
+    assert!(dev.is_continuous());
+    // already in continuous mode: nothing is done
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert_eq!(
+        -32768,
+        dev.read_normalized(ChannelSelection::DifferentialA0A1)
+            .unwrap()
+    );
+    let dev = dev.into_one_shot().ok().unwrap();
+    assert!(!dev.is_continuous());
+    dev.destroy().done();
+}
@@ -0,0 +1,131 @@
+use ads1x1x::{channel, DataRate16Bit, Error};
+use core::cell::Cell;
+
+mod common;
+use crate::common::{
+    destroy_ads1115, new_ads1115, read_config, read_conversion, write_config, BitFlags as BF,
+    Config,
+};
+
+// 128 SPS minus 10%
+const CONVERSION_TIME_US: u32 = 8681;
+
+#[test]
+fn does_not_access_bus_before_deadline() {
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&Config::default()),
+        read_config(&Config::default()),
+        read_conversion(0x1234),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let now = Cell::new(1000);
+    let mut clock = || now.get();
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(1000 + CONVERSION_TIME_US - 1);
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(1000 + CONVERSION_TIME_US);
+    assert_eq!(
+        Ok(0x1234),
+        dev.read_with_clock(channel::DifferentialA0A1, &mut clock)
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn polls_device_after_deadline() {
+    let in_progress = Config::default().with_low(BF::OS);
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&Config::default()),
+        read_config(&in_progress),
+        read_config(&Config::default()),
+        read_conversion(0x1234),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let now = Cell::new(0);
+    let mut clock = || now.get();
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(CONVERSION_TIME_US);
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    assert_eq!(
+        Ok(0x1234),
+        dev.read_with_clock(channel::DifferentialA0A1, &mut clock)
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn handles_clock_wrap_around() {
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&Config::default()),
+        read_config(&Config::default()),
+        read_conversion(0x1234),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let now = Cell::new(u32::MAX - 100);
+    let mut clock = || now.get();
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(10);
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(CONVERSION_TIME_US - 101);
+    assert_eq!(
+        Ok(0x1234),
+        dev.read_with_clock(channel::DifferentialA0A1, &mut clock)
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn deadline_depends_on_data_rate() {
+    let config = Config::default().with_high(BF::DR2 | BF::DR1 | BF::DR0);
+    let transactions = [
+        write_config(&config),
+        read_config(&config),
+        write_config(&config),
+        read_config(&config),
+        read_conversion(0x1234),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    dev.set_data_rate(DataRate16Bit::Sps860).unwrap();
+    let now = Cell::new(0);
+    let mut clock = || now.get();
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    // 860 SPS minus 10%
+    now.set(1291);
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(1292);
+    assert_eq!(
+        Ok(0x1234),
+        dev.read_with_clock(channel::DifferentialA0A1, &mut clock)
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn times_out_if_conversion_does_not_finish() {
+    let in_progress = Config::default().with_low(BF::OS);
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&Config::default()),
+        read_config(&in_progress),
+        read_config(&in_progress),
+        // a new conversion is triggered
+        read_config(&Config::default()),
+        write_config(&Config::default()),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let now = Cell::new(0);
+    let mut clock = || now.get();
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(2 * CONVERSION_TIME_US - 1);
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    now.set(2 * CONVERSION_TIME_US);
+    assert_eq!(
+        Err(nb::Error::Other(Error::Timeout)),
+        dev.read_with_clock(channel::DifferentialA0A1, &mut clock)
+    );
+    assert_would_block!(dev.read_with_clock(channel::DifferentialA0A1, &mut clock));
+    destroy_ads1115(dev);
+}
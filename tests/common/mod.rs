@@ -66,6 +66,38 @@ impl Default for Config {
     }
 }
 
+#[allow(unused)]
+pub fn write_register(register: u8, value: u16) -> I2cTrans {
+    let [msb, lsb] = value.to_be_bytes();
+    I2cTrans::write(DEVICE_ADDRESS, vec![register, msb, lsb])
+}
+
+#[allow(unused)]
+pub fn read_register(register: u8, value: u16) -> I2cTrans {
+    I2cTrans::write_read(DEVICE_ADDRESS, vec![register], value.to_be_bytes().to_vec())
+}
+
+#[allow(unused)]
+pub fn write_config(config: &Config) -> I2cTrans {
+    write_register(Register::CONFIG, config.bits)
+}
+
+#[allow(unused)]
+pub fn read_config(config: &Config) -> I2cTrans {
+    read_register(Register::CONFIG, config.bits)
+}
+
+#[allow(unused)]
+pub fn read_conversion(value: u16) -> I2cTrans {
+    read_register(Register::CONVERSION, value)
+}
+
+/// Conversion read without the register pointer, see `set_pointer_caching()`.
+#[allow(unused)]
+pub fn read_next_conversion(value: u16) -> I2cTrans {
+    I2cTrans::read(DEVICE_ADDRESS, value.to_be_bytes().to_vec())
+}
+
 macro_rules! impl_new_destroy {
     ($ic:ident, $create:ident, $destroy:ident, $conv:ty, $trans:ty, $iface:ty) => {
         #[allow(unused)]
@@ -74,7 +106,9 @@ macro_rules! impl_new_destroy {
         }
 
         #[allow(unused)]
-        pub fn $destroy<MODE>(dev: Ads1x1x<$iface, ic::$ic, $conv, MODE>) {
+        pub fn $destroy<MODE, ALERT, DELAY>(
+            dev: Ads1x1x<$iface, ic::$ic, $conv, MODE, ALERT, DELAY>,
+        ) {
             dev.$destroy().done();
         }
     };
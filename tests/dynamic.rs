@@ -0,0 +1,279 @@
+use ads1x1x::{
+    channel::ChannelSelection, DataRate, DataRate12Bit, DataRate16Bit, DynamicAdc, Error,
+    FullScaleRange,
+};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use nb::block;
+
+mod common;
+use crate::common::{
+    destroy_ads1013, destroy_ads1115, new_ads1013, new_ads1115, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn can_store_different_devices() {
+    let mut ads1013 = new_ads1013(&[]);
+    let mut ads1115 = new_ads1115(&[]);
+    {
+        let devices: [&mut dyn DynamicAdc<ErrorKind>; 2] = [&mut ads1013, &mut ads1115];
+        assert_eq!(12, devices[0].resolution_bits());
+        assert_eq!(16, devices[1].resolution_bits());
+        assert!(!devices[0].supports_channel(ChannelSelection::SingleA0));
+        assert!(devices[1].supports_channel(ChannelSelection::SingleA0));
+        assert!(!devices[0].supports_full_scale_range());
+        assert!(devices[1].supports_full_scale_range());
+        assert_eq!(
+            DataRate::Rate12Bit(DataRate12Bit::Sps1600),
+            devices[0].data_rate()
+        );
+        assert_eq!(
+            DataRate::Rate16Bit(DataRate16Bit::Sps128),
+            devices[1].data_rate()
+        );
+    }
+    destroy_ads1013(ads1013);
+    destroy_ads1115(ads1115);
+}
+
+#[test]
+fn reports_unsupported_features() {
+    let mut dev = new_ads1013(&[]);
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        assert!(matches!(
+            adc.set_full_scale_range(FullScaleRange::Within0_256V),
+            Err(Error::NotSupported)
+        ));
+        assert!(matches!(
+            adc.read_channel(ChannelSelection::SingleA0),
+            Err(nb::Error::Other(Error::NotSupported))
+        ));
+        assert!(matches!(
+            adc.set_data_rate(DataRate16Bit::Sps8.into()),
+            Err(Error::InvalidInputData)
+        ));
+        assert_eq!(FullScaleRange::Within2_048V, adc.full_scale_range());
+    }
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn can_set_configuration() {
+    let config_dr = Config::default()
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0);
+    let config_fsr = config_dr
+        .with_high(BF::PGA2)
+        .with_high(BF::PGA0)
+        .with_low(BF::PGA1);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_dr.msb(), config_dr.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_fsr.msb(), config_fsr.lsb()],
+        ),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        adc.set_data_rate(DataRate16Bit::Sps860.into()).unwrap();
+        adc.set_full_scale_range(FullScaleRange::Within0_256V)
+            .unwrap();
+        assert_eq!(DataRate::Rate16Bit(DataRate16Bit::Sps860), adc.data_rate());
+        assert_eq!(FullScaleRange::Within0_256V, adc.full_scale_range());
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_read_in_continuous_mode() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a1 = config.with_high(BF::MUX2).with_high(BF::MUX0);
+    let single_a1 = config_a1.with_high(BF::OP_MODE);
+    let stop_a1 = single_a1.with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+        // stop continuous conversions
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, stop_a1.msb(), stop_a1.lsb()],
+        ),
+        // conversion on the previous channel ongoing
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![stop_a1.msb(), stop_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        ),
+        // single conversion on the new channel
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x56, 0x78]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x1A, 0xBC]),
+    ];
+    let mut dev = new_ads1115(&transactions).into_continuous().ok().unwrap();
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        assert_eq!(
+            0x1234,
+            adc.read_channel(ChannelSelection::DifferentialA0A1)
+                .unwrap()
+        );
+        assert_eq!(
+            0x5678,
+            block!(adc.read_channel(ChannelSelection::SingleA1)).unwrap()
+        );
+        assert_eq!(
+            0x1ABC,
+            adc.read_channel(ChannelSelection::SingleA1).unwrap()
+        );
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn stale_result_is_not_reported_after_channel_change() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a1 = config.with_high(BF::MUX2).with_high(BF::MUX0);
+    let single_a1 = config_a1.with_high(BF::OP_MODE);
+    let stop_a1 = single_a1.with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, stop_a1.msb(), stop_a1.lsb()],
+        ),
+        // The conversion register still holds the result of the previous
+        // channel but the device has stopped: no result is read yet.
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![stop_a1.msb(), stop_a1.lsb()],
+        ),
+    ];
+    let mut dev = new_ads1115(&transactions).into_continuous().ok().unwrap();
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        for _ in 0..3 {
+            assert_eq!(
+                Err(nb::Error::WouldBlock),
+                adc.read_channel(ChannelSelection::SingleA1)
+            );
+        }
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn read_finishes_pending_channel_change() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a1 = config.with_high(BF::MUX2).with_high(BF::MUX0);
+    let single_a1 = config_a1.with_high(BF::OP_MODE);
+    let stop_a1 = single_a1.with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, stop_a1.msb(), stop_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+    ];
+    let mut dev = new_ads1115(&transactions).into_continuous().ok().unwrap();
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        assert_eq!(
+            Err(nb::Error::WouldBlock),
+            adc.read_channel(ChannelSelection::SingleA1)
+        );
+    }
+    assert_eq!(Ok(0x1234), dev.read());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn failed_channel_change_resumes_previous_channel() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let single_a1 = config
+        .with_high(BF::MUX2)
+        .with_high(BF::MUX0)
+        .with_high(BF::OP_MODE);
+    let stop_a1 = single_a1.with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, stop_a1.msb(), stop_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![single_a1.msb(), single_a1.lsb()],
+        )
+        .with_error(ErrorKind::Other),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+    ];
+    let mut dev = new_ads1115(&transactions).into_continuous().ok().unwrap();
+    {
+        let adc: &mut dyn DynamicAdc<ErrorKind> = &mut dev;
+        assert_eq!(
+            Err(nb::Error::WouldBlock),
+            adc.read_channel(ChannelSelection::SingleA1)
+        );
+        assert_eq!(
+            Err(nb::Error::Other(Error::I2C(ErrorKind::Other))),
+            adc.read_channel(ChannelSelection::SingleA1)
+        );
+    }
+    assert_eq!(Ok(0x1234), dev.read());
+    destroy_ads1115(dev);
+}
@@ -0,0 +1,57 @@
+use ads1x1x::{Error, ModeChangeError};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::DEVICE_ADDRESS as DEV_ADDR;
+use crate::common::{destroy_ads1013, new_ads1013, BitFlags as BF, Config, Register};
+
+#[test]
+fn can_classify_i2c_errors() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data);
+    assert_eq!(Some(nack), Error::I2C(nack).i2c_error_kind());
+    assert_eq!(None, Error::<ErrorKind>::Timeout.i2c_error_kind());
+}
+
+#[test]
+fn can_display_errors() {
+    assert_eq!(
+        "I²C bus error: ArbitrationLoss",
+        Error::I2C(ErrorKind::ArbitrationLoss).to_string()
+    );
+    assert_eq!(
+        "configuration mismatch: expected 0x8583, read 0x0583",
+        Error::<ErrorKind>::ConfigMismatch {
+            expected: 0x8583,
+            actual: 0x0583
+        }
+        .to_string()
+    );
+    let error: &dyn core::error::Error = &Error::<ErrorKind>::DeviceReset;
+    assert_eq!("device was reset", error.to_string());
+}
+
+#[test]
+fn can_get_device_back_from_mode_change_error() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions =
+        [
+            I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()])
+                .with_error(ErrorKind::Other),
+        ];
+    let dev = new_ads1013(&transactions);
+    let error = dev.into_continuous().unwrap_err();
+    assert_eq!(
+        "I²C bus error while changing mode: Other",
+        error.to_string()
+    );
+    let (e, dev) = error.into_parts();
+    assert_eq!(ErrorKind::Other, e);
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn mode_change_error_is_debug() {
+    let error: ModeChangeError<ErrorKind, ()> = ModeChangeError::I2C(ErrorKind::Bus, ());
+    assert_eq!("I2C(Bus, ())", format!("{:?}", error));
+}
@@ -0,0 +1,119 @@
+use ads1x1x::{
+    channel, channel::ChannelSelection, DataRate, DataRate12Bit, DataRate16Bit, FullScaleRange,
+};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1015, destroy_ads1115, new_ads1015, new_ads1115, read_config, write_config,
+    BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn one_shot_measurement_has_metadata() {
+    let config = Config::default().with_high(BF::MUX2 | BF::MUX0);
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&config),
+        read_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    assert_would_block!(dev.read_measurement(channel::SingleA1));
+    let measurement = dev.read_measurement(channel::SingleA1).unwrap();
+    assert_eq!(2047, measurement.raw());
+    assert_eq!(ChannelSelection::SingleA1, measurement.channel());
+    assert_eq!(FullScaleRange::Within2_048V, measurement.full_scale_range());
+    assert_eq!(
+        DataRate::Rate12Bit(DataRate12Bit::Sps1600),
+        measurement.data_rate()
+    );
+    assert_eq!(12, measurement.resolution_bits());
+    assert!(measurement.is_clipped());
+    assert_eq!(0x7FF0, measurement.normalized());
+    assert_eq!(2_047_000, measurement.microvolts());
+    assert!((measurement.volts() - 2.047).abs() < 1e-6);
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn continuous_measurement_has_metadata() {
+    let config = Config::default()
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0)
+        .with_low(BF::OP_MODE);
+    let transactions = [
+        write_config(&config.with_high(BF::OP_MODE)),
+        write_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0xC0, 0x00]),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    dev.set_full_scale_range(FullScaleRange::Within4_096V)
+        .unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let measurement = dev.read_measurement().unwrap();
+    assert_eq!(-16384, measurement.raw());
+    assert_eq!(ChannelSelection::DifferentialA0A1, measurement.channel());
+    assert_eq!(FullScaleRange::Within4_096V, measurement.full_scale_range());
+    assert_eq!(
+        DataRate::Rate16Bit(DataRate16Bit::Sps128),
+        measurement.data_rate()
+    );
+    assert_eq!(16, measurement.resolution_bits());
+    assert!(!measurement.is_clipped());
+    assert_eq!(-16384, measurement.normalized());
+    assert_eq!(-2_048_000, measurement.microvolts());
+    assert!((measurement.volts() + 2.048).abs() < 1e-6);
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn negative_full_scale_is_clipped() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        write_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let measurement = dev.read_measurement().unwrap();
+    assert!(measurement.is_clipped());
+    assert_eq!(-2_048_000, measurement.microvolts());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn normalized_results_share_scale() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        write_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x40, 0x00]),
+    ];
+    let dev = new_ads1015(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let value_12bit = dev.read_normalized().unwrap();
+    destroy_ads1015(dev);
+
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let value_16bit = dev.read_normalized().unwrap();
+    destroy_ads1115(dev);
+
+    assert_eq!(0x4000, value_12bit);
+    assert_eq!(value_12bit, value_16bit);
+}
+
+#[test]
+fn can_read_normalized_in_one_shot_mode() {
+    let config = Config::default().with_high(BF::MUX2);
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&config),
+        read_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    assert_would_block!(dev.read_normalized(channel::SingleA0));
+    assert_eq!(-32768, dev.read_normalized(channel::SingleA0).unwrap());
+    destroy_ads1015(dev);
+}
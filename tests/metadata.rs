@@ -0,0 +1,48 @@
+use ads1x1x::{ic, DataRate, DataRate12Bit, DataRate16Bit, FullScaleRange};
+
+#[test]
+fn conversion_times() {
+    assert_eq!(125_000, DataRate16Bit::Sps8.nominal_conversion_time_us());
+    assert_eq!(138_889, DataRate16Bit::Sps8.max_conversion_time_us());
+    assert_eq!(1163, DataRate16Bit::Sps860.nominal_conversion_time_us());
+    assert_eq!(304, DataRate12Bit::Sps3300.nominal_conversion_time_us());
+    assert_eq!(337, DataRate12Bit::Sps3300.max_conversion_time_us());
+    let rate = DataRate::from(DataRate12Bit::Sps128);
+    assert_eq!(7813, rate.nominal_conversion_time_us());
+    assert_eq!(8681, rate.max_conversion_time_us());
+}
+
+#[test]
+fn noise() {
+    let range = FullScaleRange::Within0_256V;
+    assert_eq!(7_812_500, DataRate16Bit::Sps8.noise_rms_picovolts(range));
+    assert_eq!(
+        7_812_500,
+        DataRate16Bit::Sps64.noise_peak_to_peak_picovolts(range)
+    );
+    assert_eq!(
+        37_060_000,
+        DataRate16Bit::Sps860.noise_peak_to_peak_picovolts(range)
+    );
+    assert_eq!(
+        252_090_000,
+        DataRate16Bit::Sps250.noise_peak_to_peak_picovolts(FullScaleRange::Within6_144V)
+    );
+    assert_eq!(16, DataRate16Bit::Sps860.effective_bits());
+    let rate = DataRate::from(DataRate12Bit::Sps3300);
+    assert_eq!(125_000_000, rate.noise_peak_to_peak_picovolts(range));
+    assert_eq!(12, rate.effective_bits());
+}
+
+#[test]
+fn ic_metadata() {
+    assert_eq!(12, ic::Ads1013::resolution_bits());
+    assert_eq!(16, ic::Ads1115::resolution_bits());
+    let range = FullScaleRange::Within2_048V;
+    assert_eq!(1_000_000_000, ic::Ads1015::lsb_picovolts(range));
+    assert_eq!(62_500_000, ic::Ads1114::lsb_picovolts(range));
+    assert_eq!(DataRate12Bit::Sps1600, ic::Ads1014::default_data_rate());
+    assert_eq!(DataRate12Bit::Sps3300, ic::Ads1015::max_data_rate());
+    assert_eq!(DataRate16Bit::Sps128, ic::Ads1113::default_data_rate());
+    assert_eq!(DataRate16Bit::Sps860, ic::Ads1115::max_data_rate());
+}
@@ -0,0 +1,93 @@
+use ads1x1x::{channel, ComparatorQueue, DataRate16Bit};
+use embedded_hal::i2c::ErrorKind;
+
+mod common;
+use crate::common::{
+    destroy_ads1015, destroy_ads1115, new_ads1015, new_ads1115, read_conversion, write_config,
+    BitFlags as BF, Config,
+};
+
+fn comparator() -> Config {
+    Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+}
+
+fn monitoring(config: &Config) -> Config {
+    config
+        .with_high(BF::MUX2 | BF::MUX0)
+        .with_low(BF::DR2 | BF::DR1 | BF::DR0)
+        .with_high(BF::COMP_LAT)
+        .with_low(BF::OP_MODE)
+}
+
+#[test]
+fn reads_triggering_value_and_restores_one_shot() {
+    let transactions = [
+        write_config(&comparator()),
+        write_config(&monitoring(&comparator())),
+        read_conversion(0x700 << 4),
+        write_config(&comparator()),
+    ];
+    let dev = new_ads1015(&transactions);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    let monitor = dev.into_monitor(channel::SingleA1).ok().unwrap();
+    let (value, dev) = monitor.on_alert().ok().unwrap();
+    assert_eq!(0x700, value);
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn can_stop_without_alert() {
+    let slow = comparator().with_low(BF::DR2).with_high(BF::DR1);
+    let transactions = [
+        write_config(&comparator()),
+        write_config(&slow),
+        write_config(&monitoring(&slow)),
+        write_config(&slow),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_data_rate(DataRate16Bit::Sps32).unwrap();
+    let monitor = dev.into_monitor(channel::SingleA1).ok().unwrap();
+    let dev = monitor.stop().ok().unwrap();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn returns_device_if_monitoring_cannot_start() {
+    let transactions = [
+        write_config(&comparator()),
+        write_config(&monitoring(&comparator())).with_error(ErrorKind::Other),
+    ];
+    let dev = new_ads1115(&transactions);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    let (error, dev) = dev
+        .into_monitor(channel::SingleA1)
+        .err()
+        .unwrap()
+        .into_parts();
+    assert_eq!(ErrorKind::Other, error);
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn keeps_monitoring_after_error() {
+    let transactions = [
+        write_config(&comparator()),
+        write_config(&monitoring(&comparator())),
+        read_conversion(0).with_error(ErrorKind::Other),
+        read_conversion(1000),
+        write_config(&comparator()).with_error(ErrorKind::Other),
+        read_conversion(1000),
+        write_config(&comparator()),
+    ];
+    let dev = new_ads1115(&transactions);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    let monitor = dev.into_monitor(channel::SingleA1).ok().unwrap();
+    let (_, monitor) = monitor.on_alert().err().unwrap().into_parts();
+    let (_, monitor) = monitor.on_alert().err().unwrap().into_parts();
+    let (value, dev) = monitor.on_alert().ok().unwrap();
+    assert_eq!(1000, value);
+    destroy_ads1115(dev);
+}
@@ -0,0 +1,78 @@
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1115, new_ads1115, read_conversion, read_next_conversion, BitFlags as BF, Config,
+    Register, DEVICE_ADDRESS as DEV_ADDR,
+};
+
+fn write_continuous() -> I2cTrans {
+    let config = Config::default().with_low(BF::OP_MODE);
+    I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()])
+}
+
+#[test]
+fn skips_pointer_on_repeated_conversion_reads() {
+    let transactions = [
+        write_continuous(),
+        read_conversion(0x1234),
+        read_next_conversion(0x2345),
+        read_next_conversion(0x3456),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.set_pointer_caching(true);
+    assert_eq!(Ok(0x1234), dev.read());
+    assert_eq!(Ok(0x2345), dev.read());
+    assert_eq!(Ok(0x3456), dev.read());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn sends_pointer_after_accessing_other_register() {
+    let transactions = [
+        write_continuous(),
+        read_conversion(0x1234),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0x85, 0x83]),
+        read_conversion(0x2345),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.set_pointer_caching(true);
+    assert_eq!(Ok(0x1234), dev.read());
+    assert!(!dev.is_measurement_in_progress().unwrap());
+    assert_eq!(Ok(0x2345), dev.read());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn sends_pointer_after_error() {
+    let transactions = [
+        write_continuous(),
+        read_conversion(0x1234),
+        read_next_conversion(0x2345).with_error(ErrorKind::Other),
+        read_conversion(0x3456),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.set_pointer_caching(true);
+    assert_eq!(Ok(0x1234), dev.read());
+    assert!(dev.read().is_err());
+    assert_eq!(Ok(0x3456), dev.read());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn sends_pointer_by_default() {
+    let transactions = [
+        write_continuous(),
+        read_conversion(0x1234),
+        read_conversion(0x2345),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert_eq!(Ok(0x1234), dev.read());
+    assert_eq!(Ok(0x2345), dev.read());
+    destroy_ads1115(dev);
+}
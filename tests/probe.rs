@@ -0,0 +1,120 @@
+use ads1x1x::{
+    probe::{probe, Confidence, Family, ProbeResult},
+    TargetAddr,
+};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+
+mod common;
+use crate::common::{read_conversion, read_register, write_register, Register};
+
+fn run(transactions: &[I2cTrans]) -> Result<ProbeResult, ErrorKind> {
+    let mut i2c = I2cMock::new(transactions);
+    let result = probe(&mut i2c, TargetAddr::default(), &mut NoopDelay::new());
+    i2c.done();
+    result
+}
+
+#[test]
+fn nack_means_no_device() {
+    let transactions = [read_register(Register::CONFIG, 0x8583)
+        .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))];
+    assert_eq!(Ok(ProbeResult::NoDevice), run(&transactions));
+}
+
+#[test]
+fn other_errors_are_returned() {
+    let transactions =
+        [read_register(Register::CONFIG, 0x8583).with_error(ErrorKind::ArbitrationLoss)];
+    assert_eq!(Err(ErrorKind::ArbitrationLoss), run(&transactions));
+}
+
+#[test]
+fn probing_error_takes_precedence_over_restore_error() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x8503).with_error(ErrorKind::ArbitrationLoss),
+        write_register(Register::CONFIG, 0x0583).with_error(ErrorKind::Bus),
+    ];
+    assert_eq!(Err(ErrorKind::ArbitrationLoss), run(&transactions));
+}
+
+#[test]
+fn restore_error_is_returned() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x1234),
+        write_register(Register::CONFIG, 0x0583).with_error(ErrorKind::Bus),
+    ];
+    assert_eq!(Err(ErrorKind::Bus), run(&transactions));
+}
+
+#[test]
+fn unexpected_readback_means_unknown_device() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x1234),
+        write_register(Register::CONFIG, 0x0583),
+    ];
+    assert_eq!(Ok(ProbeResult::Unknown), run(&transactions));
+}
+
+#[test]
+fn can_detect_12bit_device() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x8503),
+        read_conversion(0x1230),
+        write_register(Register::CONFIG, 0x0583),
+    ];
+    let expected = ProbeResult::Detected {
+        family: Family::Ads101x,
+        confidence: Confidence::High,
+    };
+    assert_eq!(Ok(expected), run(&transactions));
+}
+
+#[test]
+fn can_detect_16bit_device() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x8503),
+        read_conversion(0x1234),
+        write_register(Register::CONFIG, 0x0583),
+    ];
+    let expected = ProbeResult::Detected {
+        family: Family::Ads111x,
+        confidence: Confidence::High,
+    };
+    assert_eq!(Ok(expected), run(&transactions));
+}
+
+#[test]
+fn zero_lsbs_on_slow_device_lower_confidence() {
+    let transactions = [
+        read_register(Register::CONFIG, 0x8583),
+        write_register(Register::CONFIG, 0x8503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x0503),
+        read_register(Register::CONFIG, 0x8503),
+        read_conversion(0x1230),
+        write_register(Register::CONFIG, 0x0583),
+    ];
+    let expected = ProbeResult::Detected {
+        family: Family::Ads111x,
+        confidence: Confidence::Medium,
+    };
+    assert_eq!(Ok(expected), run(&transactions));
+}
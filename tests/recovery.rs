@@ -0,0 +1,129 @@
+use ads1x1x::{
+    recovery::{recover_bus, RecoveryError},
+    Error,
+};
+use embedded_hal::digital::ErrorKind;
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    digital::{Mock as PinMock, State, Transaction as PinTrans},
+    MockError,
+};
+
+mod common;
+use crate::common::{
+    destroy_ads1115, new_ads1115, write_register, BitFlags as BF, Config, Register,
+};
+
+// A clock pulse on SCL or the pin sequence of a STOP condition.
+fn low_high() -> [PinTrans; 2] {
+    [PinTrans::set(State::Low), PinTrans::set(State::High)]
+}
+
+fn run(scl: &[PinTrans], sda: &[PinTrans]) -> Result<u8, RecoveryError> {
+    let mut scl = PinMock::new(scl);
+    let mut sda = PinMock::new(sda);
+    let result = recover_bus(&mut scl, &mut sda, &mut NoopDelay::new());
+    scl.done();
+    sda.done();
+    result
+}
+
+#[test]
+fn only_issues_stop_if_bus_is_free() {
+    let scl = [vec![PinTrans::set(State::High)], low_high().to_vec()].concat();
+    let sda = [
+        vec![
+            PinTrans::set(State::High),
+            PinTrans::get(State::High),
+            PinTrans::get(State::High),
+        ],
+        low_high().to_vec(),
+    ]
+    .concat();
+    assert_eq!(Ok(0), run(&scl, &sda));
+}
+
+#[test]
+fn clocks_until_sda_is_released() {
+    let scl = [
+        vec![PinTrans::set(State::High)],
+        low_high().to_vec(),
+        low_high().to_vec(),
+        low_high().to_vec(),
+    ]
+    .concat();
+    let sda = [
+        vec![
+            PinTrans::set(State::High),
+            PinTrans::get(State::Low),
+            PinTrans::get(State::Low),
+            PinTrans::get(State::High),
+            PinTrans::get(State::High),
+        ],
+        low_high().to_vec(),
+    ]
+    .concat();
+    assert_eq!(Ok(2), run(&scl, &sda));
+}
+
+#[test]
+fn fails_if_sda_stays_low() {
+    let mut scl = vec![PinTrans::set(State::High)];
+    let mut sda = vec![PinTrans::set(State::High)];
+    for _ in 0..9 {
+        scl.extend(low_high());
+        sda.push(PinTrans::get(State::Low));
+    }
+    sda.push(PinTrans::get(State::Low));
+    assert_eq!(Err(RecoveryError::SdaStuckLow), run(&scl, &sda));
+}
+
+#[test]
+fn returns_pin_errors() {
+    let error = MockError::Io(std::io::ErrorKind::Other);
+    let scl = [];
+    let sda = [PinTrans::set(State::High).with_error(error)];
+    assert_eq!(Err(RecoveryError::Pin(ErrorKind::Other)), run(&scl, &sda));
+}
+
+#[test]
+fn driver_resyncs_config_after_recovery() {
+    let transactions = [
+        write_register(Register::CONFIG, Config::default().with_low(BF::OS).bits),
+        write_register(Register::LOW_TH, 0x8000),
+        write_register(Register::HIGH_TH, 0x7FFF),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let mut scl = PinMock::new(&[vec![PinTrans::set(State::High)], low_high().to_vec()].concat());
+    let mut sda = PinMock::new(
+        &[
+            vec![
+                PinTrans::set(State::High),
+                PinTrans::get(State::High),
+                PinTrans::get(State::High),
+            ],
+            low_high().to_vec(),
+        ]
+        .concat(),
+    );
+    dev.recover_bus(&mut scl, &mut sda, &mut NoopDelay::new())
+        .unwrap();
+    scl.done();
+    sda.done();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn driver_returns_recovery_error() {
+    let mut dev = new_ads1115(&[]);
+    let mut scl = PinMock::new(&[]);
+    let error = MockError::Io(std::io::ErrorKind::Other);
+    let mut sda = PinMock::new(&[PinTrans::set(State::High).with_error(error)]);
+    assert_eq!(
+        Err(Error::BusRecovery(RecoveryError::Pin(ErrorKind::Other))),
+        dev.recover_bus(&mut scl, &mut sda, &mut NoopDelay::new())
+    );
+    scl.done();
+    sda.done();
+    destroy_ads1115(dev);
+}
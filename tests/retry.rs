@@ -0,0 +1,121 @@
+use ads1x1x::{channel, Error, RetryPolicy, RetryStats};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1015, new_ads1015, read_config, write_config, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+const NACK: ErrorKind = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data);
+
+#[test]
+fn does_not_retry_by_default() {
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [write_config(&config).with_error(NACK)];
+    let mut dev = new_ads1015(&transactions);
+    assert_eq!(
+        Err(Error::I2C(NACK)),
+        dev.set_data_rate(ads1x1x::DataRate12Bit::Sps2400)
+    );
+    let expected = RetryStats {
+        retries: 0,
+        recovered: 0,
+        failures: 1,
+        consecutive_failures: 1,
+    };
+    assert_eq!(expected, dev.retry_stats());
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn retries_retryable_errors() {
+    let transactions = [
+        read_config(&Config::default()).with_error(ErrorKind::ArbitrationLoss),
+        read_config(&Config::default()).with_error(NACK),
+        read_config(&Config::default()),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    dev.set_retry_policy(RetryPolicy::new(3));
+    assert!(!dev.is_measurement_in_progress().unwrap());
+    let expected = RetryStats {
+        retries: 2,
+        recovered: 1,
+        failures: 0,
+        consecutive_failures: 0,
+    };
+    assert_eq!(expected, dev.retry_stats());
+    dev.reset_retry_stats();
+    assert_eq!(RetryStats::default(), dev.retry_stats());
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn does_not_retry_other_errors() {
+    let transactions = [read_config(&Config::default()).with_error(ErrorKind::Other)];
+    let mut dev = new_ads1015(&transactions);
+    dev.set_retry_policy(RetryPolicy::new(3));
+    assert_eq!(
+        Err(Error::I2C(ErrorKind::Other)),
+        dev.is_measurement_in_progress()
+    );
+    assert_eq!(1, dev.retry_stats().failures);
+    destroy_ads1015(dev);
+}
+
+#[derive(Default)]
+struct CountingDelay {
+    ns: u64,
+}
+
+impl DelayNs for CountingDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.ns += u64::from(ns);
+    }
+}
+
+#[test]
+fn gives_up_after_all_attempts_with_backoff() {
+    let transactions = [
+        read_config(&Config::default()).with_error(ErrorKind::Bus),
+        read_config(&Config::default()).with_error(ErrorKind::Bus),
+    ];
+    let mut delay = CountingDelay::default();
+    let mut dev = new_ads1015(&transactions).with_retry_delay(&mut delay);
+    dev.set_retry_policy(RetryPolicy::new(2).with_backoff(250));
+    assert_eq!(
+        Err(Error::I2C(ErrorKind::Bus)),
+        dev.is_measurement_in_progress()
+    );
+    let expected = RetryStats {
+        retries: 1,
+        recovered: 0,
+        failures: 1,
+        consecutive_failures: 1,
+    };
+    assert_eq!(expected, dev.retry_stats());
+    destroy_ads1015(dev);
+    assert_eq!(250_000, delay.ns);
+}
+
+#[test]
+fn one_shot_read_retries_trigger_once() {
+    let config = Config::default().with_high(BF::MUX2);
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&config).with_error(NACK),
+        write_config(&config),
+        read_config(&config.with_low(BF::OS)),
+        read_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    dev.set_retry_policy(RetryPolicy::new(2));
+    assert_would_block!(dev.read(channel::SingleA0));
+    assert_would_block!(dev.read(channel::SingleA0));
+    assert_eq!(-2048, dev.read(channel::SingleA0).unwrap());
+    assert_eq!(1, dev.retry_stats().recovered);
+    destroy_ads1015(dev);
+}
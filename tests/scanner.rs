@@ -0,0 +1,116 @@
+use ads1x1x::{channel::ChannelSelection, Error};
+
+mod common;
+use crate::common::{
+    destroy_ads1014, destroy_ads1115, new_ads1014, new_ads1115, read_conversion, write_config,
+    write_register, BitFlags as BF, Config, Register,
+};
+
+fn continuous() -> Config {
+    Config::default()
+        .with_low(BF::COMP_QUE0)
+        .with_low(BF::OP_MODE)
+}
+
+#[test]
+fn tags_results_and_discards_after_channel_change() {
+    let a0 = continuous().with_high(BF::MUX2);
+    let a1 = continuous().with_high(BF::MUX2 | BF::MUX0);
+    let transactions = [
+        write_register(Register::LOW_TH, 0),
+        write_register(Register::HIGH_TH, 0x8000),
+        write_config(&Config::default().with_low(BF::COMP_QUE0)),
+        write_config(&continuous()),
+        write_config(&a0),
+        read_conversion(1),
+        read_conversion(2),
+        read_conversion(3),
+        write_config(&a1),
+        read_conversion(4),
+        read_conversion(5),
+        read_conversion(6),
+        write_config(&a0),
+    ];
+    let dev = new_ads1115(&transactions);
+    let dev = dev.into_conversion_ready().ok().unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    {
+        let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+        let mut scanner = dev.scan(channels, 1).unwrap();
+        assert_eq!(ChannelSelection::SingleA0, scanner.selected_channel());
+        // channel of the ongoing conversion is unknown
+        assert_eq!(Ok(None), scanner.on_ready());
+        // settling
+        assert_eq!(Ok(None), scanner.on_ready());
+        assert_eq!(
+            Ok(Some((ChannelSelection::SingleA0, 3))),
+            scanner.on_ready()
+        );
+        assert_eq!(ChannelSelection::SingleA1, scanner.selected_channel());
+        // conversion started before the channel change
+        assert_eq!(
+            Ok(Some((ChannelSelection::SingleA0, 4))),
+            scanner.on_ready()
+        );
+        // settling
+        assert_eq!(Ok(None), scanner.on_ready());
+        assert_eq!(
+            Ok(Some((ChannelSelection::SingleA1, 6))),
+            scanner.on_ready()
+        );
+        assert_eq!(ChannelSelection::SingleA0, scanner.selected_channel());
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn single_channel_does_not_change_selection() {
+    let transactions = [
+        write_register(Register::LOW_TH, 0),
+        write_register(Register::HIGH_TH, 0x8000),
+        write_config(&Config::default().with_low(BF::COMP_QUE0)),
+        write_config(&continuous()),
+        read_conversion(1),
+        read_conversion(2),
+        read_conversion(3),
+    ];
+    let dev = new_ads1115(&transactions);
+    let dev = dev.into_conversion_ready().ok().unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    {
+        let mut scanner = dev.scan([ChannelSelection::DifferentialA0A1], 0).unwrap();
+        assert_eq!(Ok(None), scanner.on_ready());
+        assert_eq!(
+            Ok(Some((ChannelSelection::DifferentialA0A1, 2))),
+            scanner.on_ready()
+        );
+        assert_eq!(
+            Ok(Some((ChannelSelection::DifferentialA0A1, 3))),
+            scanner.on_ready()
+        );
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn rejects_invalid_channels() {
+    let transactions = [
+        write_register(Register::LOW_TH, 0),
+        write_register(Register::HIGH_TH, 0x8000),
+        write_config(&Config::default().with_low(BF::COMP_QUE0)),
+        write_config(&continuous()),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.into_conversion_ready().ok().unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert!(matches!(
+        dev.scan([ChannelSelection::SingleA0], 0),
+        Err(Error::NotSupported)
+    ));
+    let channels: [ChannelSelection; 0] = [];
+    assert!(matches!(
+        dev.scan(channels, 0),
+        Err(Error::InvalidInputData)
+    ));
+    destroy_ads1014(dev);
+}
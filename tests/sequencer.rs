@@ -0,0 +1,104 @@
+use ads1x1x::{channel, channel::ChannelSelection, Error};
+use core::cell::Cell;
+use embedded_hal::i2c::ErrorKind;
+
+mod common;
+use crate::common::{
+    destroy_ads1113, destroy_ads1115, new_ads1113, new_ads1115, read_config, read_conversion,
+    write_config, BitFlags as BF, Config,
+};
+
+fn a0() -> Config {
+    Config::default().with_high(BF::MUX2)
+}
+
+fn a1() -> Config {
+    Config::default().with_high(BF::MUX2 | BF::MUX0)
+}
+
+#[test]
+fn triggers_next_channel_after_reading() {
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&a0()),
+        read_config(&a0().with_low(BF::OS)),
+        read_config(&a0()),
+        read_conversion(0x1234),
+        write_config(&a1()),
+        read_config(&a1()),
+        read_conversion(0x2345),
+        write_config(&a0()),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let now = Cell::new(0);
+    {
+        let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+        let mut sequencer = dev.sequence(channels, || now.get()).unwrap();
+        assert_would_block!(sequencer.poll());
+        assert_would_block!(sequencer.poll());
+        assert_eq!(None, sequencer.throughput_sps());
+        now.set(1000);
+        assert_eq!(Ok((ChannelSelection::SingleA0, 0x1234)), sequencer.poll());
+        now.set(2000);
+        assert_eq!(Ok((ChannelSelection::SingleA1, 0x2345)), sequencer.poll());
+        assert_eq!(2, sequencer.samples());
+        assert_eq!(Some(1000), sequencer.throughput_sps());
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn uses_pending_conversion_of_first_channel() {
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&a0()),
+        read_config(&a0()),
+        read_conversion(0x1234),
+        write_config(&a1()),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    assert_would_block!(dev.read(channel::SingleA0));
+    {
+        let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+        let mut sequencer = dev.sequence(channels, || 0).unwrap();
+        assert_eq!(Ok((ChannelSelection::SingleA0, 0x1234)), sequencer.poll());
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn retriggers_after_trigger_error() {
+    let transactions = [
+        read_config(&Config::default()),
+        write_config(&a0()),
+        read_config(&a0()),
+        read_conversion(0x1234),
+        write_config(&a1()).with_error(ErrorKind::Other),
+        read_config(&a0()),
+        write_config(&a1()),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    {
+        let channels = [ChannelSelection::SingleA0, ChannelSelection::SingleA1];
+        let mut sequencer = dev.sequence(channels, || 0).unwrap();
+        assert_would_block!(sequencer.poll());
+        assert_eq!(Ok((ChannelSelection::SingleA0, 0x1234)), sequencer.poll());
+        assert_would_block!(sequencer.poll());
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn rejects_invalid_channels() {
+    let mut dev = new_ads1113(&[]);
+    assert!(matches!(
+        dev.sequence([ChannelSelection::SingleA0], || 0),
+        Err(Error::NotSupported)
+    ));
+    let channels: [ChannelSelection; 0] = [];
+    assert!(matches!(
+        dev.sequence(channels, || 0),
+        Err(Error::InvalidInputData)
+    ));
+    destroy_ads1113(dev);
+}
@@ -0,0 +1,101 @@
+use ads1x1x::{
+    channel::ChannelSelection,
+    soft_comparator::{AlertEvent, ComparatorBank, SoftComparator},
+    ComparatorLatching, ComparatorMode, ComparatorQueue,
+};
+
+const CH: ChannelSelection = ChannelSelection::SingleA0;
+
+fn asserted(value: i16) -> Option<AlertEvent> {
+    Some(AlertEvent::Asserted { channel: CH, value })
+}
+
+fn cleared(value: i16) -> Option<AlertEvent> {
+    Some(AlertEvent::Cleared { channel: CH, value })
+}
+
+#[test]
+fn traditional_comparator_has_hysteresis() {
+    let mut comparator = SoftComparator::new(CH, 100, 200).with_queue(ComparatorQueue::One);
+    assert_eq!(None, comparator.update(200));
+    assert_eq!(asserted(201), comparator.update(201));
+    assert!(comparator.is_asserted());
+    assert_eq!(None, comparator.update(150));
+    assert_eq!(None, comparator.update(100));
+    assert_eq!(cleared(99), comparator.update(99));
+    assert!(!comparator.is_asserted());
+}
+
+#[test]
+fn window_comparator_asserts_outside_of_window() {
+    let mut comparator = SoftComparator::new(CH, 100, 200)
+        .with_mode(ComparatorMode::Window)
+        .with_queue(ComparatorQueue::One);
+    assert_eq!(asserted(99), comparator.update(99));
+    assert_eq!(None, comparator.update(201));
+    assert_eq!(cleared(150), comparator.update(150));
+    assert_eq!(asserted(201), comparator.update(201));
+}
+
+#[test]
+fn queue_needs_consecutive_results() {
+    let mut comparator = SoftComparator::new(CH, 100, 200).with_queue(ComparatorQueue::Two);
+    assert_eq!(None, comparator.update(300));
+    assert_eq!(None, comparator.update(150));
+    assert_eq!(None, comparator.update(300));
+    assert_eq!(asserted(301), comparator.update(301));
+
+    let mut comparator = SoftComparator::new(CH, 100, 200);
+    for _ in 0..3 {
+        assert_eq!(None, comparator.update(300));
+    }
+    assert_eq!(asserted(300), comparator.update(300));
+}
+
+#[test]
+fn latched_alert_remains_until_cleared() {
+    let mut comparator = SoftComparator::new(CH, 100, 200)
+        .with_queue(ComparatorQueue::One)
+        .with_latching(ComparatorLatching::Latching);
+    assert_eq!(asserted(300), comparator.update(300));
+    assert_eq!(None, comparator.update(0));
+    assert!(comparator.is_asserted());
+    comparator.clear_latch();
+    assert!(!comparator.is_asserted());
+    assert_eq!(None, comparator.update(0));
+    assert_eq!(asserted(300), comparator.update(300));
+}
+
+#[test]
+fn bank_tracks_channels_independently() {
+    let mut bank = ComparatorBank::new([
+        SoftComparator::new(ChannelSelection::SingleA0, 100, 200),
+        SoftComparator::new(ChannelSelection::SingleA1, -200, -100)
+            .with_queue(ComparatorQueue::One),
+    ]);
+    assert_eq!(None, bank.update(ChannelSelection::SingleA0, 300));
+    assert_eq!(
+        Some(AlertEvent::Asserted {
+            channel: ChannelSelection::SingleA1,
+            value: 0
+        }),
+        bank.update(ChannelSelection::SingleA1, 0)
+    );
+    assert_eq!(None, bank.update(ChannelSelection::SingleA2, 300));
+    assert_eq!(
+        vec![ChannelSelection::SingleA1],
+        bank.asserted().collect::<Vec<_>>()
+    );
+    assert!(!bank.get(ChannelSelection::SingleA0).unwrap().is_asserted());
+    assert!(bank.get(ChannelSelection::SingleA2).is_none());
+
+    bank.get_mut(ChannelSelection::SingleA0)
+        .unwrap()
+        .set_thresholds(0, 10);
+    assert_eq!(
+        (0, 10),
+        bank.get(ChannelSelection::SingleA0).unwrap().thresholds()
+    );
+    bank.clear_latches();
+    assert_eq!(0, bank.asserted().count());
+}
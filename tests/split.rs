@@ -0,0 +1,104 @@
+use ads1x1x::channel;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1115 as destroy, new_ads1115 as new, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn handles_serialise_conversions() {
+    let default_config = Config::default();
+    let config_a0 = Config::default().with_high(BF::MUX2);
+    let config_a1 = Config::default().with_high(BF::MUX2).with_high(BF::MUX0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x56, 0x78]),
+    ];
+    let shared = new(&transactions).split();
+    {
+        let mut a0 = shared.channel(channel::SingleA0);
+        let mut a1 = shared.channel(channel::SingleA1);
+        assert_would_block!(a0.read());
+        // conversion requested by a0 pending: no bus access
+        assert_would_block!(a1.read());
+        assert_eq!(0x1234, a0.read().unwrap());
+        assert_would_block!(a1.read());
+        assert_eq!(0x5678, a1.read().unwrap());
+    }
+    destroy(shared.release());
+}
+
+#[test]
+fn dropped_handle_releases_device() {
+    let config_a0 = Config::default().with_high(BF::MUX2);
+    let config_a1 = Config::default().with_high(BF::MUX2).with_high(BF::MUX0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a0.msb(), config_a0.lsb()],
+        ),
+        // conversion of a0 finished but never read: discarded
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x56, 0x78]),
+    ];
+    let shared = new(&transactions).split();
+    {
+        let mut a1 = shared.channel(channel::SingleA1);
+        {
+            let mut a0 = shared.channel(channel::SingleA0);
+            assert_would_block!(a0.read());
+            assert_would_block!(a1.read());
+        }
+        assert_would_block!(a1.read());
+        assert_eq!(0x5678, a1.read().unwrap());
+    }
+    destroy(shared.release());
+}
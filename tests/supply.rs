@@ -0,0 +1,96 @@
+use ads1x1x::{ComparatorQueue, FullScaleRange};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1115, new_ads1115, write_config, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn supply_is_not_declared_by_default() {
+    let dev = new_ads1115(&[]);
+    assert_eq!(None, dev.supply_millivolts());
+    assert_eq!(-32768..=32767, dev.usable_code_range());
+    assert!(!dev.full_scale_range_exceeds_supply());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn reports_usable_code_range() {
+    let config = Config::default().with_low(BF::PGA1);
+    let transactions = [write_config(&config)];
+    let dev = new_ads1115(&transactions).with_supply_millivolts(3300);
+    assert_eq!(Some(3300), dev.supply_millivolts());
+    assert_eq!(-32768..=32767, dev.usable_code_range());
+    assert!(!dev.full_scale_range_exceeds_supply());
+
+    let mut dev = dev;
+    dev.set_full_scale_range(FullScaleRange::Within6_144V)
+        .unwrap();
+    assert_eq!(-20800..=20800, dev.usable_code_range());
+    assert!(dev.full_scale_range_exceeds_supply());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn reports_unreachable_thresholds() {
+    let config = Config::default().with_low(BF::PGA1);
+    let comparator = config.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0);
+    let transactions = [
+        write_config(&config),
+        write_config(&comparator),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0xAE, 0xC0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x51, 0x41]),
+        write_config(&comparator.with_high(BF::PGA0)),
+    ];
+    let mut dev = new_ads1115(&transactions).with_supply_millivolts(3300);
+    dev.set_full_scale_range(FullScaleRange::Within6_144V)
+        .unwrap();
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!((false, false), dev.thresholds_reachable());
+    dev.set_low_threshold_raw(-20800).unwrap();
+    dev.set_high_threshold_raw(20801).unwrap();
+    assert_eq!((true, false), dev.thresholds_reachable());
+    // 3.9V / 125µV
+    dev.set_full_scale_range(FullScaleRange::Within4_096V)
+        .unwrap();
+    assert_eq!((true, true), dev.thresholds_reachable());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn flags_measurement_at_supply_limit() {
+    let config = Config::default().with_low(BF::PGA1);
+    let transactions = [
+        write_config(&config),
+        write_config(&config.with_low(BF::OP_MODE)),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x51, 0x40]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x51, 0x3F]),
+    ];
+    let mut dev = new_ads1115(&transactions).with_supply_millivolts(3300);
+    dev.set_full_scale_range(FullScaleRange::Within6_144V)
+        .unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let measurement = dev.read_measurement().unwrap();
+    assert!(measurement.is_at_supply_limit());
+    assert!(!measurement.is_clipped());
+    let measurement = dev.read_measurement().unwrap();
+    assert!(!measurement.is_at_supply_limit());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn full_scale_result_is_not_at_supply_limit() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        write_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xFF]),
+    ];
+    let dev = new_ads1115(&transactions).with_supply_millivolts(3300);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let measurement = dev.read_measurement().unwrap();
+    assert!(measurement.is_clipped());
+    assert!(!measurement.is_at_supply_limit());
+    destroy_ads1115(dev);
+}
@@ -0,0 +1,70 @@
+use ads1x1x::{
+    mux::{MuxError, Tca9548a},
+    Ads1x1x, Error, TargetAddr,
+};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+mod common;
+use crate::common::{BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR};
+
+const MUX_ADDR: u8 = 0x70;
+
+#[test]
+fn segment_index_must_be_valid() {
+    let mux = Tca9548a::new(I2cMock::new(&[]), MUX_ADDR);
+    assert_eq!(Some(7), mux.segment(7).map(|s| s.index()));
+    assert!(mux.segment(8).is_none());
+    mux.destroy().done();
+}
+
+#[test]
+fn selects_segment_only_when_needed() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(MUX_ADDR, vec![0b0000_0100]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(MUX_ADDR, vec![0b0000_1000]),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write(MUX_ADDR, vec![0b0000_0100]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xFF]),
+    ];
+    let mux = Tca9548a::new(I2cMock::new(&transactions), MUX_ADDR);
+    {
+        let adc = Ads1x1x::new_ads1115(mux.segment(2).unwrap(), TargetAddr::default());
+        let mut other = Ads1x1x::new_ads1115(mux.segment(3).unwrap(), TargetAddr::default());
+        let mut adc = adc.into_continuous().ok().unwrap();
+        assert_eq!(-32768, adc.read().unwrap());
+        assert!(!other.is_measurement_in_progress().unwrap());
+        assert_eq!(32767, adc.read().unwrap());
+    }
+    mux.destroy().done();
+}
+
+#[test]
+fn reports_selection_errors() {
+    let transactions = [
+        I2cTrans::write(MUX_ADDR, vec![0b0000_0001]).with_error(ErrorKind::Other),
+        I2cTrans::write(MUX_ADDR, vec![0b0000_0001]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0x85, 0x83])
+            .with_error(ErrorKind::Other),
+    ];
+    let mux = Tca9548a::new(I2cMock::new(&transactions), MUX_ADDR);
+    {
+        let mut adc = Ads1x1x::new_ads1015(mux.segment(0).unwrap(), TargetAddr::default());
+        match adc.is_measurement_in_progress() {
+            Err(Error::I2C(MuxError::Select(ErrorKind::Other))) => (),
+            _ => panic!("Selection error not reported."),
+        }
+        match adc.is_measurement_in_progress() {
+            Err(Error::I2C(MuxError::Device(ErrorKind::Other))) => (),
+            _ => panic!("Device error not reported."),
+        }
+    }
+    mux.destroy().done();
+}
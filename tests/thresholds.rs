@@ -0,0 +1,204 @@
+use ads1x1x::{ComparatorMode, ComparatorQueue, Error};
+
+mod common;
+use crate::common::{
+    destroy_ads1015, destroy_ads1115, new_ads1015, new_ads1115, read_register, write_register,
+    BitFlags as BF, Config, Register,
+};
+
+fn comparator() -> Config {
+    Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+}
+
+#[test]
+fn writes_thresholds_in_safe_order() {
+    let transactions = [
+        write_register(Register::CONFIG, comparator().bits),
+        // Lowering the upper threshold: lower threshold first
+        write_register(Register::LOW_TH, 500),
+        write_register(Register::HIGH_TH, 1000),
+        // Raising the upper threshold: upper threshold first
+        write_register(Register::HIGH_TH, 2000),
+        write_register(Register::LOW_TH, 1500),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_thresholds_raw(500, 1000).unwrap();
+    dev.set_thresholds_raw(1500, 2000).unwrap();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn rejects_invalid_threshold_ordering() {
+    let transactions = [write_register(Register::CONFIG, comparator().bits)];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!(Err(Error::InvalidInputData), dev.set_thresholds_raw(10, 10));
+    assert_eq!(Err(Error::InvalidInputData), dev.set_thresholds_raw(10, 5));
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn rejects_thresholds_out_of_resolution_limits() {
+    let transactions = [write_register(Register::CONFIG, comparator().bits)];
+    let dev = new_ads1015(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_thresholds_raw(0, 2048)
+    );
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_thresholds_raw(-2049, 0)
+    );
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn can_set_trip_point_with_hysteresis() {
+    let transactions = [
+        write_register(Register::CONFIG, comparator().bits),
+        write_register(Register::LOW_TH, 900 << 4),
+        write_register(Register::HIGH_TH, 1000 << 4),
+    ];
+    let dev = new_ads1015(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_trip_point_raw(1000, 100).unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point_raw(1000, 0)
+    );
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point_raw(-2000, 100)
+    );
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn can_set_thresholds_in_microvolts() {
+    let transactions = [
+        write_register(Register::CONFIG, comparator().bits),
+        write_register(Register::LOW_TH, 900 << 4),
+        write_register(Register::HIGH_TH, 1000 << 4),
+        write_register(Register::HIGH_TH, 1500 << 4),
+        write_register(Register::LOW_TH, (-2048_i16 << 4) as u16),
+    ];
+    let dev = new_ads1015(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    // 1mV per LSB
+    dev.set_trip_point_microvolts(1_000_000, 100_400).unwrap();
+    dev.set_high_threshold_microvolts(1_500_499).unwrap();
+    dev.set_low_threshold_microvolts(-2_048_000).unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_low_threshold_microvolts(-2_049_000)
+    );
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point_microvolts(1_000_000, u32::MAX)
+    );
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn trip_point_does_not_overflow() {
+    let transactions = [write_register(Register::CONFIG, comparator().bits)];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point_raw(-32700, 100)
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_set_window() {
+    let window = comparator().with_high(BF::COMP_MODE);
+    let transactions = [
+        write_register(Register::CONFIG, comparator().bits),
+        write_register(Register::CONFIG, window.bits),
+        write_register(Register::LOW_TH, 950),
+        write_register(Register::HIGH_TH, 1051),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!(Err(Error::InvalidInputData), dev.set_window_raw(1000, 101));
+    dev.set_comparator_mode(ComparatorMode::Window).unwrap();
+    dev.set_window_raw(1000, 101).unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point_raw(1000, 100)
+    );
+    assert_eq!(Err(Error::InvalidInputData), dev.set_window_raw(32700, 200));
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_read_back_thresholds() {
+    let transactions = [
+        read_register(Register::LOW_TH, 0xFC18),
+        read_register(Register::HIGH_TH, 1000),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    assert_eq!(Ok((-1000, 1000)), dev.read_thresholds_raw());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_read_back_thresholds_in_microvolts() {
+    let transactions = [
+        read_register(Register::LOW_TH, 0xFC18),
+        read_register(Register::HIGH_TH, 1000),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    assert_eq!(Ok((-62500, 62500)), dev.read_thresholds_microvolts());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn read_back_handles_12_bit_shift() {
+    let transactions = [
+        read_register(Register::LOW_TH, 0x8000),
+        read_register(Register::HIGH_TH, 0x7FFF),
+        read_register(Register::LOW_TH, 0xFFF0),
+        read_register(Register::HIGH_TH, 1000 << 4),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    assert_eq!(Ok((-2048, 2047)), dev.read_thresholds_raw());
+    assert_eq!(Ok((-1, 1000)), dev.read_thresholds_raw());
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn thresholds_read_back_are_not_restored() {
+    let transactions = [
+        read_register(Register::LOW_TH, 500),
+        read_register(Register::HIGH_TH, 1000),
+        write_register(Register::CONFIG, Config::default().with_low(BF::OS).bits),
+        write_register(Register::LOW_TH, 0x8000),
+        write_register(Register::HIGH_TH, 0x7FFF),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    dev.read_thresholds_raw().unwrap();
+    dev.resync_config().unwrap();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn adopted_thresholds_are_restored() {
+    let transactions = [
+        read_register(Register::LOW_TH, 500),
+        read_register(Register::HIGH_TH, 1000),
+        write_register(Register::CONFIG, Config::default().with_low(BF::OS).bits),
+        write_register(Register::LOW_TH, 500),
+        write_register(Register::HIGH_TH, 1000),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    dev.adopt_device_thresholds().unwrap();
+    dev.resync_config().unwrap();
+    destroy_ads1115(dev);
+}
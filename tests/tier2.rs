@@ -1,6 +1,8 @@
 use ads1x1x::{
-    ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue, FullScaleRange,
+    AlertRdyFunction, ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue,
+    FullScaleRange,
 };
+use embedded_hal::i2c::ErrorKind;
 use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
 
 mod common;
@@ -26,10 +28,28 @@ macro_rules! config_test {
     };
 }
 
-mod can_set_comparator_thresholds {
-    use super::*;
-    set_value_test!(low, set_low_threshold_raw, 2047, LOW_TH, 0x7F, 0xF0);
-    set_value_test!(high, set_high_threshold_raw, 2047, HIGH_TH, 0x7F, 0xF0);
+fn enable_comparator(queue: &Config) -> I2cTrans {
+    I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, queue.msb(), queue.lsb()])
+}
+
+fn comparator_one() -> Config {
+    Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+}
+
+#[test]
+fn can_set_comparator_thresholds() {
+    let transactions = [
+        enable_comparator(&comparator_one()),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x80, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x7F, 0xF0]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_low_threshold_raw(-2048).unwrap();
+    dev.set_high_threshold_raw(2047).unwrap();
+    destroy_ads1014(dev);
 }
 
 mod can_set_comparator_mode {
@@ -82,39 +102,45 @@ mod can_set_comparator_latching {
 
 #[test]
 fn can_disable_comparator() {
-    let config = Config::default()
-        .with_high(BF::COMP_QUE1)
-        .with_high(BF::COMP_QUE0);
-    let transactions = [I2cTrans::write(
-        DEV_ADDR,
-        vec![Register::CONFIG, config.msb(), config.lsb()],
-    )];
-    let mut dev = new_ads1014(&transactions);
-    dev.disable_comparator().unwrap();
+    let disabled = Config::default();
+    let transactions = [
+        enable_comparator(&comparator_one()),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, disabled.msb(), disabled.lsb()],
+        ),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    let dev = dev.into_alert_rdy_disabled().ok().unwrap();
     destroy_ads1014(dev);
 }
 
-mod can_set_comparator_queue {
+mod can_enable_comparator {
     use super::*;
-    config_test!(
-        one,
-        set_comparator_queue,
-        ComparatorQueue::One,
-        Config::default()
-            .with_low(BF::COMP_QUE1)
-            .with_low(BF::COMP_QUE0)
-    );
-    config_test!(
+
+    macro_rules! queue_test {
+        ($name:ident, $queue:expr, $config:expr) => {
+            #[test]
+            fn $name() {
+                let transactions = [enable_comparator(&$config)];
+                let dev = new_ads1014(&transactions);
+                let dev = dev.into_comparator($queue).ok().unwrap();
+                destroy_ads1014(dev);
+            }
+        };
+    }
+
+    queue_test!(one, ComparatorQueue::One, comparator_one());
+    queue_test!(
         two,
-        set_comparator_queue,
         ComparatorQueue::Two,
         Config::default()
             .with_low(BF::COMP_QUE1)
             .with_high(BF::COMP_QUE0)
     );
-    config_test!(
+    queue_test!(
         four,
-        set_comparator_queue,
         ComparatorQueue::Four,
         Config::default()
             .with_high(BF::COMP_QUE1)
@@ -123,43 +149,138 @@ mod can_set_comparator_queue {
 }
 
 #[test]
-fn can_use_alert_rdy_pin_as_rdy_does_not_disable_comparator_if_already_disabled() {
+fn can_set_comparator_queue() {
+    let two = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_high(BF::COMP_QUE0);
+    let transactions = [
+        enable_comparator(&comparator_one()),
+        enable_comparator(&two),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_comparator_queue(ComparatorQueue::Two).unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_use_alert_rdy_pin_as_rdy_enables_comparator() {
+    let four = Config::default().with_low(BF::COMP_QUE0);
     let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
         I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        enable_comparator(&four),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.into_conversion_ready().ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_use_alert_rdy_pin_as_rdy_keeps_comparator_queue() {
+    let transactions = [
+        enable_comparator(&comparator_one()),
         I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
     ];
-    let mut dev = new_ads1014(&transactions);
-    dev.use_alert_rdy_pin_as_ready().unwrap();
+    let dev = new_ads1014(&transactions);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    let dev = dev.into_conversion_ready().ok().unwrap();
     destroy_ads1014(dev);
 }
 
 #[test]
-fn can_use_alert_rdy_pin_as_rdy_disabled_comparator() {
-    let config = Config::default()
-        .with_low(BF::COMP_QUE1)
-        .with_low(BF::COMP_QUE0);
-    let config_disabled_comp = Config::default()
-        .with_high(BF::COMP_QUE1)
-        .with_low(BF::COMP_QUE0);
+fn comparator_after_rdy_resets_thresholds() {
+    let four = Config::default().with_low(BF::COMP_QUE0);
     let transactions = [
-        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
-        I2cTrans::write(
-            DEV_ADDR,
-            vec![
-                Register::CONFIG,
-                config_disabled_comp.msb(),
-                config_disabled_comp.lsb(),
-            ],
-        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
         I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        enable_comparator(&four),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x7F, 0xFF]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x80, 0x00]),
+        enable_comparator(&comparator_one()),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.into_conversion_ready().ok().unwrap();
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn failed_transition_returns_device() {
+    let transactions = [
+        enable_comparator(&comparator_one()).with_error(ErrorKind::Other),
+        enable_comparator(&comparator_one()),
+    ];
+    let dev = new_ads1014(&transactions);
+    let (error, dev) = dev
+        .into_comparator(ComparatorQueue::One)
+        .err()
+        .unwrap()
+        .into_parts();
+    assert_eq!(ErrorKind::Other, error);
+    let dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn failed_transition_keeps_track_of_written_registers() {
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0])
+            .with_error(ErrorKind::Other),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, 0x05, 0x83]),
         I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x7F, 0xFF]),
     ];
-    let mut dev = new_ads1014(&transactions);
-    dev.set_comparator_queue(ComparatorQueue::One).unwrap();
-    dev.use_alert_rdy_pin_as_ready().unwrap();
+    let dev = new_ads1014(&transactions);
+    let (error, mut dev) = dev.into_conversion_ready().err().unwrap().into_parts();
+    assert_eq!(ErrorKind::Other, error);
+    dev.resync_config().unwrap();
     destroy_ads1014(dev);
 }
 
+mod can_read_alert_rdy_function {
+    use super::*;
+
+    fn read(register: u8, value: u16) -> I2cTrans {
+        I2cTrans::write_read(DEV_ADDR, vec![register], value.to_be_bytes().to_vec())
+    }
+
+    fn run(transactions: &[I2cTrans]) -> AlertRdyFunction {
+        let mut dev = new_ads1014(transactions);
+        let function = dev.read_alert_rdy_function().unwrap();
+        destroy_ads1014(dev);
+        function
+    }
+
+    #[test]
+    fn disabled() {
+        let transactions = [read(Register::CONFIG, Config::default().bits)];
+        assert_eq!(AlertRdyFunction::Disabled, run(&transactions));
+    }
+
+    #[test]
+    fn comparator() {
+        let transactions = [
+            read(Register::CONFIG, comparator_one().bits),
+            read(Register::HIGH_TH, 0x7FFF),
+            read(Register::LOW_TH, 0x8000),
+        ];
+        assert_eq!(AlertRdyFunction::Comparator, run(&transactions));
+    }
+
+    #[test]
+    fn conversion_ready() {
+        let transactions = [
+            read(Register::CONFIG, comparator_one().bits),
+            read(Register::HIGH_TH, 0x8000),
+            read(Register::LOW_TH, 0),
+        ];
+        assert_eq!(AlertRdyFunction::ConversionReady, run(&transactions));
+    }
+}
+
 mod can_set_full_scale_range {
     use super::*;
     config_test!(
@@ -0,0 +1,144 @@
+#![cfg(feature = "uom")]
+
+use ads1x1x::{channel, ComparatorQueue, Error, FullScaleRange};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use uom::si::{
+    electric_potential::{microvolt, millivolt, volt},
+    f32::ElectricPotential,
+};
+
+mod common;
+use crate::common::{
+    destroy_ads1015, destroy_ads1115, new_ads1015, new_ads1115, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+fn mv(value: f32) -> ElectricPotential {
+    ElectricPotential::new::<millivolt>(value)
+}
+
+macro_rules! assert_close {
+    ($expected:expr, $actual:expr) => {
+        let (expected, actual): (f32, f32) = ($expected, $actual);
+        assert!(
+            (expected - actual).abs() <= expected.abs() * 1e-6,
+            "{} != {}",
+            expected,
+            actual
+        );
+    };
+}
+
+#[test]
+fn full_scale_range_metadata() {
+    assert_close!(
+        6.144,
+        FullScaleRange::Within6_144V.full_scale().get::<volt>()
+    );
+    assert_close!(3.0, FullScaleRange::Within6_144V.lsb(12).get::<millivolt>());
+    assert_close!(
+        7.8125,
+        FullScaleRange::Within0_256V.lsb(16).get::<microvolt>()
+    );
+}
+
+#[test]
+fn code_voltage_round_trip() {
+    let fsr = FullScaleRange::Within2_048V;
+    assert_close!(1000.0, fsr.code_to_voltage(1000, 12).get::<millivolt>());
+    assert_eq!(Some(1000), fsr.voltage_to_code(mv(1000.4), 12));
+    assert_eq!(Some(-1001), fsr.voltage_to_code(mv(-1000.6), 12));
+    assert_eq!(Some(2047), fsr.voltage_to_code(mv(2047.4), 12));
+    assert_eq!(Some(-2048), fsr.voltage_to_code(mv(-2048.0), 12));
+    assert_eq!(None, fsr.voltage_to_code(mv(2047.6), 12));
+    assert_eq!(None, fsr.voltage_to_code(mv(-2048.6), 12));
+    assert_eq!(None, fsr.voltage_to_code(mv(f32::NAN), 12));
+    assert_eq!(Some(16000), fsr.voltage_to_code(mv(1000.0), 16));
+}
+
+#[test]
+fn can_read_voltage_in_one_shot_mode() {
+    let transactions = [
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0x85, 0x83]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, 0x85, 0x83]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0x85, 0x83]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x3E, 0x80]),
+    ];
+    let mut dev = new_ads1015(&transactions);
+    assert_would_block!(dev.read_voltage(channel::DifferentialA0A1));
+    let voltage = dev.read_voltage(channel::DifferentialA0A1).unwrap();
+    assert_close!(1000.0, voltage.get::<millivolt>());
+    destroy_ads1015(dev);
+}
+
+#[test]
+fn can_read_voltage_in_continuous_mode() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0xC1, 0x80]),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let voltage = dev.read_voltage().unwrap();
+    assert_close!(-1000.0, voltage.get::<millivolt>());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_set_thresholds() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0xC1, 0x80]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x3E, 0x80]),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_low_threshold(mv(-1000.0)).unwrap();
+    dev.set_high_threshold(mv(1000.0)).unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_high_threshold(mv(3000.0))
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_set_trip_point() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x38, 0x40]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x3E, 0x80]),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_trip_point(mv(1000.0), mv(100.0)).unwrap();
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_trip_point(mv(1000.0), mv(-100.0))
+    );
+    assert_eq!(
+        Err(Error::InvalidInputData),
+        dev.set_window(mv(0.0), mv(100.0))
+    );
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn can_read_back_thresholds() {
+    let transactions = [
+        I2cTrans::write_read(DEV_ADDR, vec![Register::LOW_TH], vec![0xC1, 0x80]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::HIGH_TH], vec![0x3E, 0x80]),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    let (low, high) = dev.read_thresholds().unwrap();
+    assert_close!(-1000.0, low.get::<millivolt>());
+    assert_close!(1000.0, high.get::<millivolt>());
+    destroy_ads1115(dev);
+}
@@ -0,0 +1,90 @@
+use ads1x1x::{channel, ComparatorQueue, ConfigWatchdog, Error};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+
+mod common;
+use crate::common::{
+    destroy_ads1115, new_ads1115, read_config, write_register, BitFlags as BF, Config, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn verify_matching_config() {
+    let transactions = [read_config(&Config::default())];
+    let mut dev = new_ads1115(&transactions);
+    dev.verify_config().unwrap();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn verify_ignores_os_bit() {
+    let transactions = [read_config(&Config::default().with_low(BF::OS))];
+    let mut dev = new_ads1115(&transactions);
+    dev.verify_config().unwrap();
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn detects_reset_and_restores_config_in_continuous_mode() {
+    let comparator = Config::default().with_low(BF::COMP_QUE0);
+    let config = comparator.with_low(BF::OP_MODE);
+    let transactions = [
+        write_register(Register::CONFIG, comparator.bits),
+        write_register(Register::HIGH_TH, 1000),
+        write_register(Register::CONFIG, config.bits),
+        read_config(&Config::default()),
+        write_register(Register::CONFIG, config.with_low(BF::OS).bits),
+        write_register(Register::LOW_TH, 0x8000),
+        write_register(Register::HIGH_TH, 1000),
+        read_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+    ];
+    let dev = new_ads1115(&transactions);
+    let mut dev = dev.into_comparator(ComparatorQueue::Four).ok().unwrap();
+    dev.set_high_threshold_raw(1000).unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.set_config_watchdog(ConfigWatchdog::EveryRead);
+    assert_eq!(Err(Error::DeviceReset), dev.read());
+    assert_eq!(Ok(0x1234), dev.read());
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn detects_mismatch_in_one_shot_mode() {
+    let config = Config::default().with_high(BF::MUX2);
+    let other = Config::default().with_high(BF::COMP_MODE);
+    let transactions = [
+        read_config(&Config::default()),
+        write_register(Register::CONFIG, config.bits),
+        read_config(&other),
+        write_register(Register::CONFIG, config.with_low(BF::OS).bits),
+        write_register(Register::LOW_TH, 0x8000),
+        write_register(Register::HIGH_TH, 0x7FFF),
+    ];
+    let mut dev = new_ads1115(&transactions);
+    dev.set_config_watchdog(ConfigWatchdog::EveryRead);
+    assert_would_block!(dev.read(channel::SingleA0));
+    match dev.read(channel::SingleA0) {
+        Err(nb::Error::Other(Error::ConfigMismatch { expected, actual })) => {
+            assert_eq!(config.with_low(BF::OS).bits, expected);
+            assert_eq!(other.bits, actual);
+        }
+        _ => panic!("Mismatch not reported."),
+    }
+    destroy_ads1115(dev);
+}
+
+#[test]
+fn verifies_every_n_reads() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        write_register(Register::CONFIG, config.bits),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x34]),
+        read_config(&config),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x12, 0x35]),
+    ];
+    let mut dev = new_ads1115(&transactions).into_continuous().ok().unwrap();
+    dev.set_config_watchdog(ConfigWatchdog::EveryNReads(2));
+    assert_eq!(Ok(0x1234), dev.read());
+    assert_eq!(Ok(0x1235), dev.read());
+    destroy_ads1115(dev);
+}